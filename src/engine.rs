@@ -1,99 +1,370 @@
 use std::{
-    cell::RefCell,
-    collections::HashSet,
-    fmt::Debug,
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display},
     hash::Hash,
-    iter::Sum,
+    iter::{Sum, zip},
     ops::{Add, Div, Mul, Neg, Sub},
     rc::Rc,
 };
 
-#[derive(PartialEq, Eq)]
+thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+    // 현재 thread에 살아 있는 TensorData 개수 (leak 확인용)
+    static LIVE_TENSORS: Cell<usize> = const { Cell::new(0) };
+}
+
+// 현재 thread에서 만들어진 뒤 아직 해제되지 않은 Tensor 노드 수
+// 학습 루프 전후로 비교하면 그래프가 새는지 확인할 수 있음
+pub fn live_tensors() -> usize {
+    LIVE_TENSORS.with(|count| count.get())
+}
+
+// 현재 thread에서 연산이 그래프를 기록하는지 여부
+pub fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(|enabled| enabled.get())
+}
+
+// f 안에서 만든 연산 결과는 _prev와 _backward가 없는 leaf가 됨
+// 추론, metric 계산, 파라미터 업데이트 등 backward가 필요 없는 곳에 사용
+pub fn no_grad<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    // panic이 나도 원래 상태로 돌려놓기 위한 guard
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            GRAD_ENABLED.with(|enabled| enabled.set(self.0));
+        }
+    }
+
+    let _restore = Restore(GRAD_ENABLED.with(|enabled| enabled.replace(false)));
+    f()
+}
+
+// 여러 root에서 한 번의 위상 정렬로 backward (합 노드를 따로 만들지 않는 multi-task loss 등)
+// 각 root는 seed로 시작하고, 다른 root를 거쳐 들어오는 기울기와 더해짐
+pub fn backward_many<T: Float>(seeds: &[(Tensor<T>, T)]) {
+    run_backward(seeds, true);
+}
+
+// retain_graph가 false면 처리가 끝난 중간 노드의 _prev, _backward를 지워서
+// root가 살아 있어도 그래프가 해제되도록 함 (이후 그 노드들은 leaf가 됨)
+#[allow(clippy::mutable_key_type)]
+fn run_backward<T: Float>(seeds: &[(Tensor<T>, T)], retain_graph: bool) {
+    let roots: Vec<Tensor<T>> = seeds.iter().map(|(root, _)| root.clone()).collect();
+    let mut todos = Tensor::topological_sort_many(&roots);
+    todos.reverse();
+
+    // root의 기울기는 seed로 시작하고, 나머지 노드의 기울기는 누적됨
+    // hook이 있는 노드는 이번 backward의 기울기만 hook에 넘기도록 기존 값을 따로 보관
+    for root in &roots {
+        root.set_grad(T::zero());
+    }
+    let mut accumulated = HashMap::new();
+    for node in &todos {
+        if node.has_hooks() {
+            accumulated.insert(node.clone(), node.grad());
+            node.set_grad(T::zero());
+        }
+    }
+    for (root, seed) in seeds {
+        root.set_grad(root.grad() + *seed);
+    }
+
+    // 역위상 순서이므로 node 차례가 되면 node로 들어올 기울기는 모두 더해진 상태
+    for node in &todos {
+        if node.has_hooks() {
+            node.run_hooks();
+            if let Some(old) = accumulated.get(node) {
+                node.set_grad(node.grad() + *old);
+            }
+        }
+
+        let backward = node.0.borrow()._backward;
+        if let Some(f) = backward {
+            f(node);
+            if !retain_graph {
+                node.release();
+            }
+        }
+    }
+}
+
+mod custom;
+mod draw;
+mod dual;
+mod expr;
+mod float;
+mod gradcheck;
+mod jacobian;
+mod matvec;
+mod nd;
+mod parse;
+mod program;
+mod rules;
+mod serialize;
+mod softmax;
+mod tape;
+
+pub use custom::CustomOp;
+pub use dual::{Dual, jvp};
+pub use expr::{Expr, Func};
+pub use float::Float;
+pub use gradcheck::{GradCheckEntry, GradCheckReport, gradcheck};
+pub use jacobian::{hessian, jacobian};
+pub use nd::NdTensor;
+pub use parse::{ParseError, parse};
+pub use program::{OptimizeReport, Program};
+pub use softmax::{log_softmax, logsumexp, softmax};
+pub use tape::{Tape, Var};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 enum Operation {
     None,
     Neg,
     Add,
     Mul,
     Pow,
+    Powf(f64),
     Tanh,
     Exp,
+    Relu,
+    LeakyRelu(f64),
+    Sigmoid,
+    Log,
+    Sqrt,
+    Abs,
+    Sin,
+    Cos,
+    Softplus,
+    Gelu,
+    Silu,
+    Elu(f64),
+    Dot,
+    Max,
+    Min,
+    Clamp(f64, f64),
+    // _prev = [cond, a, b]
+    Where,
+    // _prev = [x_i, logsumexp(x)], logsumexp 노드는 같은 입력의 출력끼리 공유
+    Softmax,
+    LogSoftmax,
+    // _prev는 입력 전체
+    LogSumExp,
+    // w x 를 한꺼번에 계산하는 공유 노드, _prev = [w (행 우선), x], 행 수를 보관
+    // 값은 출력(Row) 노드에 있고 이 노드의 data는 0
+    MatVec(usize),
+    // MatVec 노드의 i번째 출력, _prev = [MatVec 노드]
+    Row(usize),
+    // Program::optimize 가 x * y^-1 을 합친 나눗셈
+    Div,
+    // 사용자 정의 연산, 실제 연산은 TensorData::_custom 에 있음
+    Custom,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::None => write!(f, ""),
+            Operation::Neg => write!(f, "neg"),
+            Operation::Add => write!(f, "+"),
+            Operation::Mul => write!(f, "*"),
+            Operation::Pow => write!(f, "pow"),
+            Operation::Powf(n) => write!(f, "**{}", n),
+            Operation::Tanh => write!(f, "tanh"),
+            Operation::Exp => write!(f, "exp"),
+            Operation::Relu => write!(f, "relu"),
+            Operation::LeakyRelu(alpha) => write!(f, "leaky_relu({})", alpha),
+            Operation::Sigmoid => write!(f, "sigmoid"),
+            Operation::Log => write!(f, "log"),
+            Operation::Sqrt => write!(f, "sqrt"),
+            Operation::Abs => write!(f, "abs"),
+            Operation::Sin => write!(f, "sin"),
+            Operation::Cos => write!(f, "cos"),
+            Operation::Softplus => write!(f, "softplus"),
+            Operation::Gelu => write!(f, "gelu"),
+            Operation::Silu => write!(f, "silu"),
+            Operation::Elu(alpha) => write!(f, "elu({})", alpha),
+            Operation::Dot => write!(f, "dot"),
+            Operation::Max => write!(f, "max"),
+            Operation::Min => write!(f, "min"),
+            Operation::Clamp(lo, hi) => write!(f, "clamp({}, {})", lo, hi),
+            Operation::Where => write!(f, "where"),
+            Operation::Softmax => write!(f, "softmax"),
+            Operation::LogSoftmax => write!(f, "log_softmax"),
+            Operation::LogSumExp => write!(f, "logsumexp"),
+            Operation::MatVec(_) => write!(f, "matvec"),
+            Operation::Row(i) => write!(f, "row[{}]", i),
+            Operation::Div => write!(f, "/"),
+            Operation::Custom => write!(f, "custom"),
+        }
+    }
 }
 
 // 실제 데이터를 담고 있는 내부 구조체
-struct TensorData {
-    data: f64,
-    grad: f64,
+struct TensorData<T: Float> {
+    data: T,
+    grad: T,
     label: String,
 
-    _backward: Option<fn(&Tensor)>,
-    _prev: Vec<Tensor>,
+    _backward: Option<fn(&Tensor<T>)>,
+    _prev: Vec<Tensor<T>>,
     _op: Operation,
+    _custom: Option<Rc<dyn CustomOp<T>>>,
+    _hooks: Vec<GradHook<T>>,
+    // 스칼라 연산에서 만든 상수 leaf (Program::optimize 에서 접어서 없앰)
+    _constant: bool,
+    // MatVec 노드가 출력별 기울기를 모으는 곳 (Row의 backward가 채우고 MatVec의 backward가 비움)
+    _row_grads: Vec<T>,
+}
+
+// backward 중 기울기가 확정되었을 때 호출, 반환값이 새 기울기가 됨
+type GradHook<T> = Rc<dyn Fn(T) -> T>;
+
+// 기본 Drop은 _prev를 따라 재귀적으로 내려가므로 깊은 그래프에서 stack overflow가 남
+// 마지막 참조인 부모 노드들을 꺼내서 반복문으로 해제
+impl<T: Float> Drop for TensorData<T> {
+    fn drop(&mut self) {
+        LIVE_TENSORS.with(|count| count.set(count.get() - 1));
+
+        let mut stack = std::mem::take(&mut self._prev);
+        while let Some(tensor) = stack.pop() {
+            if let Ok(cell) = Rc::try_unwrap(tensor.0) {
+                let mut data = cell.into_inner();
+                stack.append(&mut data._prev);
+            }
+        }
+    }
+}
+
+// Tensor::graph_stats 결과
+// bytes는 노드 구조체, _prev 배열, label 만 센 근사값 (Rc 헤더, hook 등은 제외)
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct GraphStats {
+    pub nodes: usize,
+    pub leaves: usize,
+    pub edges: usize,
+    pub bytes: usize,
 }
 
 // 사용자가 다룰 Tensor 구조체 (스마트 포인터 래퍼)
+// 기본은 f64, 속도와 메모리가 중요하면 Tensor<f32>
 #[derive(Clone)]
-pub struct Tensor(Rc<RefCell<TensorData>>);
+pub struct Tensor<T: Float = f64>(Rc<RefCell<TensorData<T>>>);
 
 // for hashing
 // Rc가 가리키는 포인터 주소를 비교하는 방식
-impl PartialEq for Tensor {
+impl<T: Float> PartialEq for Tensor<T> {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
-impl Eq for Tensor {}
+impl<T: Float> Eq for Tensor<T> {}
 
-impl Hash for Tensor {
+impl<T: Float> Hash for Tensor<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.0.as_ptr().hash(state);
     }
 }
 
+// 리터럴만으로 만든 그래프도 타입 추론이 되도록 기존 생성자는 f64 전용으로 둠
 impl Tensor {
     pub fn new(data: f64) -> Self {
+        Self::leaf(data)
+    }
+
+    pub fn new_with_label(data: f64, label: &str) -> Self {
+        Self::leaf_with_label(data, label)
+    }
+
+    pub fn from_vec(datas: Vec<f64>) -> Vec<Self> {
+        Self::leaves(datas)
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    // 임의의 Float 타입 leaf (e.g. Tensor::<f32>::leaf(1.0))
+    pub fn leaf(data: T) -> Self {
+        LIVE_TENSORS.with(|count| count.set(count.get() + 1));
         Tensor(Rc::new(RefCell::new(TensorData {
             data,
-            grad: 0.0,
+            grad: T::zero(),
             label: String::new(),
             _backward: None,
             _prev: vec![],
             _op: Operation::None,
+            _custom: None,
+            _hooks: vec![],
+            _constant: false,
+            _row_grads: vec![],
         })))
     }
 
-    pub fn new_with_label(data: f64, label: &str) -> Self {
-        let tensor = Self::new(data);
+    // 기울기가 필요 없는 상수 (&a + 1.0 의 1.0 등)
+    pub fn constant(data: T) -> Self {
+        let tensor = Self::leaf(data);
+        tensor.0.borrow_mut()._constant = true;
+        tensor
+    }
+
+    pub fn leaf_with_label(data: T, label: &str) -> Self {
+        let tensor = Self::leaf(data);
         tensor.0.borrow_mut().label = label.into();
         tensor
     }
 
-    fn new_with_operation(data: f64, operation: Operation) -> Self {
-        let tensor = Self::new(data);
-        tensor.0.borrow_mut()._op = operation;
+    // 연산 결과 노드 생성
+    // no_grad 안에서는 기록 없이 leaf로 만듦
+    fn new_with_operation(
+        data: T,
+        operation: Operation,
+        prev: Vec<Tensor<T>>,
+        backward: fn(&Tensor<T>),
+    ) -> Self {
+        let tensor = Self::leaf(data);
+        if is_grad_enabled() {
+            let mut t = tensor.0.borrow_mut();
+            t._op = operation;
+            t._prev = prev;
+            t._backward = Some(backward);
+        }
         tensor
     }
 
-    pub fn from_vec(datas: Vec<f64>) -> Vec<Self> {
-        datas.iter().map(|d| Tensor::new(*d)).collect()
+    // 같은 값을 가진, 그래프와 끊어진 새 leaf
+    pub fn detach(&self) -> Tensor<T> {
+        Tensor::leaf_with_label(self.data(), &self.label())
+    }
+
+    pub fn leaves(datas: Vec<T>) -> Vec<Self> {
+        datas.iter().map(|d| Tensor::leaf(*d)).collect()
     }
 
     // 데이터 조회
-    pub fn data(&self) -> f64 {
+    pub fn data(&self) -> T {
         self.0.borrow().data
     }
-    pub fn grad(&self) -> f64 {
+    pub fn grad(&self) -> T {
         self.0.borrow().grad
     }
-    pub fn prev(&self) -> Vec<Tensor> {
+    pub fn prev(&self) -> Vec<Tensor<T>> {
         self.0.borrow()._prev.clone()
     }
     pub fn label(&self) -> String {
         self.0.borrow().label.clone()
     }
+    pub fn is_constant(&self) -> bool {
+        self.0.borrow()._constant
+    }
+    pub fn is_leaf(&self) -> bool {
+        self.0.borrow()._prev.is_empty()
+    }
 
     // setter
-    pub fn set_grad(&self, grad: f64) {
+    pub fn set_grad(&self, grad: T) {
         self.0.borrow_mut().grad = grad;
     }
     pub fn set_label(&self, label: &str) {
@@ -102,100 +373,351 @@ impl Tensor {
 
     // caller
     pub fn backward(&self) {
+        self.backward_with(T::one());
+    }
+
+    // root의 기울기를 1 대신 seed로 시작 (vector-Jacobian product 등)
+    pub fn backward_with(&self, seed: T) {
+        backward_many(&[(self.clone(), seed)]);
+    }
+
+    // retain_graph = false 이면 backward 후 중간 노드들의 연결을 끊어서 메모리를 바로 돌려줌
+    // 같은 그래프로 다시 backward 할 수 없음 (root 자신도 leaf가 됨)
+    pub fn backward_retain(&self, retain_graph: bool) {
+        run_backward(&[(self.clone(), T::one())], retain_graph);
+    }
+
+    // 그래프에서 떼어내서 leaf로 만듦 (값과 기울기는 유지)
+    fn release(&self) {
+        let prev = {
+            let mut t = self.0.borrow_mut();
+            t._backward = None;
+            t._op = Operation::None;
+            t._custom = None;
+            std::mem::take(&mut t._prev)
+        };
+        // 부모의 해제는 borrow를 놓은 뒤에
+        drop(prev);
+    }
+
+    // 이 노드에서 도달할 수 있는 노드 수 (자신 포함)
+    pub fn graph_size(&self) -> usize {
+        self.topological_sort().len()
+    }
+
+    pub fn graph_stats(&self) -> GraphStats {
+        let nodes = self.topological_sort();
+        let mut stats = GraphStats {
+            nodes: nodes.len(),
+            ..Default::default()
+        };
+        for node in &nodes {
+            let t = node.0.borrow();
+            if t._prev.is_empty() {
+                stats.leaves += 1;
+            }
+            stats.edges += t._prev.len();
+            stats.bytes += size_of::<TensorData<T>>()
+                + t._prev.capacity() * size_of::<Tensor<T>>()
+                + t.label.capacity();
+        }
+        stats
+    }
+
+    // backward에서 이 노드의 기울기가 확정되면 호출 (_prev로 전달되기 전)
+    // 기울기를 기록하거나, clip 하거나, 바꿀 때 사용
+    // 여러 개를 등록하면 등록한 순서대로 이어서 적용
+    // backward_create_graph 에서는 호출되지 않음
+    pub fn register_hook(&self, hook: impl Fn(T) -> T + 'static) {
+        self.0.borrow_mut()._hooks.push(Rc::new(hook));
+    }
+
+    pub fn clear_hooks(&self) {
+        self.0.borrow_mut()._hooks.clear();
+    }
+
+    fn has_hooks(&self) -> bool {
+        !self.0.borrow()._hooks.is_empty()
+    }
+
+    fn run_hooks(&self) {
+        let hooks = self.0.borrow()._hooks.clone();
+        let grad = hooks.iter().fold(self.grad(), |grad, hook| hook(grad));
+        self.0.borrow_mut().grad = grad;
+    }
+
+    // self를 inputs로 미분한 기울기를 값 대신 Tensor 그래프로 만들어서 inputs 순서대로 반환
+    // 반환된 기울기를 다시 미분할 수 있음 (2계 미분, Hessian-vector product, gradient penalty 등)
+    // inputs의 grad()에는 backward처럼 값이 누적되고, 다른 노드의 grad()는 바뀌지 않음
+    // 기울기 그래프는 노드에 저장하지 않으므로 반환값을 버리면 해제됨
+    // 그래프에 없는 입력의 기울기는 상수 0
+    // 그래프에 CustomOp 노드가 있으면 panic
+    #[allow(clippy::mutable_key_type)]
+    pub fn backward_create_graph(&self, inputs: &[Tensor<T>]) -> Vec<Tensor<T>> {
         let mut todos = self.topological_sort();
         todos.reverse();
 
-        self.set_grad(1.0);
+        let mut grads: HashMap<Tensor<T>, Tensor<T>> = HashMap::new();
+        grads.insert(self.clone(), Tensor::leaf(T::one()));
 
+        // 역위상 순서이므로 node 차례가 되면 node의 기울기 그래프는 완성된 상태
         for node in &todos {
-            match node.0.borrow()._backward {
-                Some(f) => f(&node),
-                None => (),
+            let Some(grad) = grads.get(node).cloned() else {
+                continue;
+            };
+            let (operation, prev) = node.scalar_view();
+            for (p, g) in prev.iter().zip(node.grad_graph_of(operation, &prev, &grad)) {
+                let acc = match grads.remove(p) {
+                    Some(acc) => &acc + &g,
+                    None => g,
+                };
+                grads.insert(p.clone(), acc);
             }
         }
-    }
 
-    pub fn topological_sort(&self) -> Vec<Tensor> {
+        inputs
+            .iter()
+            .map(|x| match grads.get(x) {
+                Some(grad) => {
+                    x.set_grad(x.grad() + grad.data());
+                    grad.clone()
+                }
+                None => Tensor::constant(T::zero()),
+            })
+            .collect()
+    }
+
+    // _backward와 같은 규칙을 Tensor 연산으로 표현 (scalar_view의 입력 순서대로 기울기 반환)
+    fn grad_graph_of(
+        &self,
+        operation: Operation,
+        prev: &[Tensor<T>],
+        grad: &Tensor<T>,
+    ) -> Vec<Tensor<T>> {
+        let mut locals = Vec::with_capacity(prev.len());
+        match operation {
+            // 사용자 정의 연산의 backward는 값만 돌려주므로 기울기를 그래프로 만들 수 없음
+            // (상수로 취급하면 이 연산을 지나는 2차 미분이 조용히 0이 됨)
+            Operation::Custom => panic!(
+                "backward_create_graph cannot differentiate through custom op {}",
+                self.op_label()
+            ),
+            operation => rules::partials(operation, prev, self, &mut locals),
+        }
+        locals.iter().map(|local| grad * local).collect()
+    }
+
+    // rules로 계산할 수 있는 (연산, 입력)
+    // backward_create_graph, Program, 기호 미분은 MatVec의 출력을 그 행과 x의 dot으로 다루고
+    // MatVec 노드 자신은 건너뜀 (기울기 그래프가 이 노드를 거치지 않음)
+    fn scalar_view(&self) -> (Operation, Vec<Tensor<T>>) {
+        let (operation, prev) = (self.operation(), self.prev());
+        let Operation::Row(i) = operation else {
+            return (operation, prev);
+        };
+        // backward_retain(false)로 MatVec 노드만 먼저 해제된 경우
+        let Operation::MatVec(rows) = prev[0].operation() else {
+            panic!("matvec output used after its graph was released");
+        };
+        let inputs = prev[0].prev();
+        let n = inputs.len() / (rows + 1);
+        let (w, x) = inputs.split_at(rows * n);
+        (Operation::Dot, [&w[i * n..(i + 1) * n], x].concat())
+    }
+
+    // 재귀 대신 명시적인 stack을 사용하므로 그래프가 아주 깊어도 stack overflow가 나지 않음
+    pub fn topological_sort(&self) -> Vec<Tensor<T>> {
+        Tensor::topological_sort_many(std::slice::from_ref(self))
+    }
+
+    // 여러 root의 그래프를 합친 위상 정렬 (공유하는 노드는 한 번만 포함)
+    #[allow(clippy::mutable_key_type)]
+    fn topological_sort_many(roots: &[Tensor<T>]) -> Vec<Tensor<T>> {
         let mut visited = HashSet::new();
         let mut todo = Vec::new();
 
-        self._build_todo(&mut visited, &mut todo);
+        // (node, 부모 노드들을 모두 처리했는지 여부)
+        let mut stack: Vec<_> = roots.iter().rev().map(|r| (r.clone(), false)).collect();
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                todo.push(node);
+                continue;
+            }
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+
+            let prev = node.prev();
+            stack.push((node, true));
+            // 재귀 버전과 같은 순서로 방문하도록 역순으로 push
+            for p in prev.into_iter().rev() {
+                if !visited.contains(&p) {
+                    stack.push((p, false));
+                }
+            }
+        }
 
         todo
     }
 
-    fn _build_todo(&self, visited: &mut HashSet<Tensor>, todo: &mut Vec<Tensor>) {
-        if !visited.contains(self) {
-            visited.insert(self.clone());
+    pub fn tanh(&self) -> Tensor<T> {
+        self.unary(Operation::Tanh)
+    }
 
-            for p in &self.prev() {
-                p._build_todo(visited, todo);
-            }
+    // x.pow(2.0) 처럼 상수 지수, x.pow(&y) 처럼 Tensor 지수 모두 가능
+    pub fn pow(&self, rhs: impl Exponent<T>) -> Tensor<T> {
+        rhs.pow_of(self)
+    }
 
-            todo.push(self.clone());
-        }
+    // 지수가 상수인 경우, 지수는 그래프에 넣지 않고 Operation에 보관
+    fn pow_const(&self, rhs: T) -> Tensor<T> {
+        self.unary(Operation::Powf(rhs.to_f64()))
     }
 
-    pub fn tanh(&self) -> Tensor {
-        let e_2x = (self.data() * 2.0).exp();
-        let out = Tensor::new_with_operation((e_2x - 1.0) / (e_2x + 1.0), Operation::Tanh);
-        out.0.borrow_mut()._prev = vec![self.clone()];
+    // 지수도 Tensor인 경우 (x^y), 양쪽 모두로 기울기 전달
+    // x <= 0 에서는 지수 쪽 기울기를 0으로 둔다.
+    // (x < 0 이면 x^y는 정수 y에서만 실수이므로 y 방향으로 미분할 수 없음)
+    fn pow_tensor(&self, rhs: &Tensor<T>) -> Tensor<T> {
+        Tensor::from_operation(Operation::Pow, vec![self.clone(), rhs.clone()])
+    }
 
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            for p in &prev {
-                p.set_grad(p.grad() + (1.0 - out.data().powi(2)) * out.grad());
-            }
-        }
+    pub fn exp(&self) -> Tensor<T> {
+        self.unary(Operation::Exp)
+    }
 
-        out.0.borrow_mut()._backward = Some(_backward);
+    // 값과 기울기를 rules의 규칙으로 계산하는 연산 노드
+    fn from_operation(operation: Operation, prev: Vec<Tensor<T>>) -> Tensor<T> {
+        let x: Vec<T> = prev.iter().map(|p| p.data()).collect();
+        Tensor::new_with_operation(rules::value(operation, &x), operation, prev, _backward)
+    }
 
-        out
+    // 입력이 하나인 연산의 공통 부분
+    fn unary(&self, operation: Operation) -> Tensor<T> {
+        Tensor::from_operation(operation, vec![self.clone()])
     }
 
-    // i64 or f64
-    pub fn pow(&self, rhs: f64) -> Tensor {
-        let rhs = Tensor::new(rhs);
-        let out = Tensor::new_with_operation(self.data().powf(rhs.data()), Operation::Pow);
-        out.0.borrow_mut()._prev = vec![self.clone(), rhs.clone()];
+    fn operation(&self) -> Operation {
+        self.0.borrow()._op
+    }
 
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            let l = &prev[0];
-            let r = &prev[1];
+    fn custom_op(&self) -> Option<Rc<dyn CustomOp<T>>> {
+        self.0.borrow()._custom.clone()
+    }
 
-            l.set_grad(l.grad() + (r.data() * l.data().powf(r.data() - 1.0)) * out.grad());
+    // 그래프 출력용 연산 이름 (사용자 정의 연산은 CustomOp::name)
+    fn op_label(&self) -> String {
+        match self.custom_op() {
+            Some(op) => op.name().to_string(),
+            None => self.operation().to_string(),
         }
+    }
 
-        out.0.borrow_mut()._backward = Some(_backward);
+    pub fn relu(&self) -> Tensor<T> {
+        self.unary(Operation::Relu)
+    }
 
-        out
+    // x > 0 이면 x, 아니면 alpha * x
+    pub fn leaky_relu(&self, alpha: T) -> Tensor<T> {
+        self.unary(Operation::LeakyRelu(alpha.to_f64()))
     }
 
-    pub fn exp(&self) -> Tensor {
-        let x = self.data();
-        let out = Tensor::new_with_operation(x.exp(), Operation::Exp);
-        out.0.borrow_mut()._prev = vec![self.clone()];
+    pub fn sigmoid(&self) -> Tensor<T> {
+        self.unary(Operation::Sigmoid)
+    }
 
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            for p in &prev {
-                p.set_grad(p.grad() + out.data() * out.grad());
-            }
-        }
+    // 자연로그
+    pub fn log(&self) -> Tensor<T> {
+        self.unary(Operation::Log)
+    }
+
+    pub fn sqrt(&self) -> Tensor<T> {
+        self.unary(Operation::Sqrt)
+    }
+
+    // x = 0 에서의 subgradient는 0
+    pub fn abs(&self) -> Tensor<T> {
+        self.unary(Operation::Abs)
+    }
+
+    pub fn sin(&self) -> Tensor<T> {
+        self.unary(Operation::Sin)
+    }
 
-        out.0.borrow_mut()._backward = Some(_backward);
+    pub fn cos(&self) -> Tensor<T> {
+        self.unary(Operation::Cos)
+    }
+
+    // ln(1 + e^x)
+    pub fn softplus(&self) -> Tensor<T> {
+        self.unary(Operation::Softplus)
+    }
+
+    // tanh 근사식을 사용
+    pub fn gelu(&self) -> Tensor<T> {
+        self.unary(Operation::Gelu)
+    }
+
+    // x * sigmoid(x)
+    pub fn silu(&self) -> Tensor<T> {
+        self.unary(Operation::Silu)
+    }
+
+    // x > 0 이면 x, 아니면 alpha * (e^x - 1)
+    pub fn elu(&self, alpha: T) -> Tensor<T> {
+        self.unary(Operation::Elu(alpha.to_f64()))
+    }
+
+    // sum(a_i * b_i) 를 노드 하나로 계산 (Mul, Add 노드를 2n개 만들지 않음)
+    // _prev 에는 a 다음 b 순서로 들어감
+    pub fn dot(a: &[Tensor<T>], b: &[Tensor<T>]) -> Tensor<T> {
+        assert_eq!(a.len(), b.len(), "dot requires slices of the same length");
+        Tensor::from_operation(Operation::Dot, [a, b].concat())
+    }
 
-        out
+    // 두 값이 같으면 기울기를 양쪽에 반씩 나눔 (subgradient 구간의 가운데)
+    pub fn max(&self, other: &Tensor<T>) -> Tensor<T> {
+        Tensor::from_operation(Operation::Max, vec![self.clone(), other.clone()])
+    }
+
+    // max와 같이 두 값이 같으면 반씩 나눔
+    pub fn min(&self, other: &Tensor<T>) -> Tensor<T> {
+        Tensor::from_operation(Operation::Min, vec![self.clone(), other.clone()])
+    }
+
+    // lo < x < hi 이면 기울기를 그대로 전달, 범위 밖에서는 0
+    // 경계에서는 max, min과 같이 반만 전달 (min(max(x, lo), hi)와 같은 기울기)
+    pub fn clamp(&self, lo: T, hi: T) -> Tensor<T> {
+        assert!(lo <= hi, "clamp requires lo <= hi");
+        self.unary(Operation::Clamp(lo.to_f64(), hi.to_f64()))
+    }
+
+    // cond가 0이 아니면 a, 0이면 b
+    // 기울기는 선택된 쪽으로만 가고 cond로는 가지 않음 (cond에 대해 구간별 상수)
+    // cond도 _prev에 넣어 두므로 Program으로 다시 실행하면 cond도 다시 계산됨
+    pub fn where_(cond: &Tensor<T>, a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> {
+        Tensor::from_operation(Operation::Where, vec![cond.clone(), a.clone(), b.clone()])
     }
 
     // temporal functions
-    pub fn set_data(&self, data: f64) {
+    pub fn set_data(&self, data: T) {
         self.0.borrow_mut().data = data;
     }
 }
 
-impl Debug for Tensor {
+// 기본 연산의 backward: rules의 국소 기울기에 출력 기울기를 곱해서 부모에 누적
+fn _backward<T: Float>(out: &Tensor<T>) {
+    let prev = out.prev();
+    let x: Vec<T> = prev.iter().map(|p| p.data()).collect();
+    let mut locals = Vec::with_capacity(prev.len());
+    rules::partials(out.operation(), &x, &out.data(), &mut locals);
+
+    let grad = out.grad();
+    for (p, local) in zip(&prev, locals) {
+        p.set_grad(p.grad() + local * grad);
+    }
+}
+
+impl<T: Float> Debug for Tensor<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -208,28 +730,16 @@ impl Debug for Tensor {
 }
 
 // ---- Add 구현
-impl Add for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Add for &Tensor<T> {
+    type Output = Tensor<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let out = Tensor::new_with_operation(self.data() + rhs.data(), Operation::Add);
-        out.0.borrow_mut()._prev = vec![self.clone(), rhs.clone()];
-
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            for p in &prev {
-                p.set_grad(p.grad() + 1.0 * out.grad());
-            }
-        }
-
-        out.0.borrow_mut()._backward = Some(_backward);
-
-        out
+        Tensor::from_operation(Operation::Add, vec![self.clone(), rhs.clone()])
     }
 }
 
-impl Add for Tensor {
-    type Output = Tensor;
+impl<T: Float> Add for Tensor<T> {
+    type Output = Tensor<T>;
 
     // 재사용
     fn add(self, rhs: Self) -> Self::Output {
@@ -238,127 +748,86 @@ impl Add for Tensor {
 }
 
 // in python, radd
-impl Add<f64> for Tensor {
-    type Output = Tensor;
+impl<T: Float> Add<T> for Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn add(self, rhs: f64) -> Self::Output {
-        let rhs = Tensor::new(rhs);
+    fn add(self, rhs: T) -> Self::Output {
+        let rhs = Tensor::constant(rhs);
         &self + &rhs
     }
 }
 
-impl Add<f64> for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Add<T> for &Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn add(self, rhs: f64) -> Self::Output {
-        let rhs = Tensor::new(rhs);
+    fn add(self, rhs: T) -> Self::Output {
+        let rhs = Tensor::constant(rhs);
         self + &rhs
     }
 }
 
-impl Add<&Tensor> for f64 {
-    type Output = Tensor;
-
-    fn add(self, rhs: &Tensor) -> Self::Output {
-        let temp = Tensor::new(self);
-        &temp + rhs
-    }
-}
-
 // ---- Mul 구현
-impl Mul for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul for &Tensor<T> {
+    type Output = Tensor<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let out = Tensor::new_with_operation(self.data() * rhs.data(), Operation::Mul);
-        out.0.borrow_mut()._prev = vec![self.clone(), rhs.clone()];
-
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            let l = &prev[0];
-            let r = &prev[1];
-            l.set_grad(l.grad() + r.data() * out.grad());
-            r.set_grad(r.grad() + l.data() * out.grad());
-        }
-
-        out.0.borrow_mut()._backward = Some(_backward);
-
-        out
+        Tensor::from_operation(Operation::Mul, vec![self.clone(), rhs.clone()])
     }
 }
 
-impl Mul for Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul for Tensor<T> {
+    type Output = Tensor<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         &self * &rhs
     }
 }
 
-impl Mul<&Tensor> for Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul<&Tensor<T>> for Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn mul(self, rhs: &Tensor) -> Self::Output {
+    fn mul(self, rhs: &Tensor<T>) -> Self::Output {
         &self * rhs
     }
 }
 
-impl Mul<Tensor> for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul<Tensor<T>> for &Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn mul(self, rhs: Tensor) -> Self::Output {
+    fn mul(self, rhs: Tensor<T>) -> Self::Output {
         self * &rhs
     }
 }
 
-impl Mul<f64> for Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul<T> for Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        let rhs = Tensor::new(rhs);
+    fn mul(self, rhs: T) -> Self::Output {
+        let rhs = Tensor::constant(rhs);
         &self * rhs
     }
 }
 
-impl Mul<f64> for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Mul<T> for &Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        let rhs = Tensor::new(rhs);
+    fn mul(self, rhs: T) -> Self::Output {
+        let rhs = Tensor::constant(rhs);
         self * rhs
     }
 }
 
-// in python, rmul
-impl Mul<Tensor> for f64 {
-    type Output = Tensor;
-
-    fn mul(self, rhs: Tensor) -> Self::Output {
-        let temp = Tensor::new(self);
-        temp * rhs
-    }
-}
-
-impl Mul<&Tensor> for f64 {
-    type Output = Tensor;
-
-    fn mul(self, rhs: &Tensor) -> Self::Output {
-        let temp = Tensor::new(self);
-        temp * rhs
-    }
-}
-
 // Div
-impl Div for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Div for &Tensor<T> {
+    type Output = Tensor<T>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.pow(-1.0)
+        self * rhs.pow(-T::one())
     }
 }
 
-impl Div for Tensor {
-    type Output = Tensor;
+impl<T: Float> Div for Tensor<T> {
+    type Output = Tensor<T>;
 
     fn div(self, rhs: Self) -> Self::Output {
         &self / &rhs
@@ -366,52 +835,101 @@ impl Div for Tensor {
 }
 
 // ---- Sub
-impl Neg for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Neg for &Tensor<T> {
+    type Output = Tensor<T>;
 
     fn neg(self) -> Self::Output {
-        let out = Tensor::new_with_operation(-self.data(), Operation::Neg);
-        out.0.borrow_mut()._prev = vec![self.clone()];
-
-        fn _backward(out: &Tensor) {
-            let prev = out.prev();
-            for p in prev {
-                p.set_grad(p.grad() + -1.0 * out.grad());
-            }
-        }
-        out.0.borrow_mut()._backward = Some(_backward);
-
-        out
+        self.unary(Operation::Neg)
     }
 }
 
-impl Sub for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Sub for &Tensor<T> {
+    type Output = Tensor<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         self + &(-rhs)
     }
 }
 
-impl Sub<f64> for &Tensor {
-    type Output = Tensor;
+impl<T: Float> Sub<T> for &Tensor<T> {
+    type Output = Tensor<T>;
 
-    fn sub(self, rhs: f64) -> Self::Output {
+    fn sub(self, rhs: T) -> Self::Output {
         self + -rhs
     }
 }
 
-impl Sub<&Tensor> for f64 {
-    type Output = Tensor;
+// 스칼라가 왼쪽에 오는 연산 (in python, radd / rmul / rsub)
+// 제네릭으로는 구현할 수 없으므로 타입마다 구현
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {
+        $(
+            impl Add<&Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn add(self, rhs: &Tensor<$t>) -> Self::Output {
+                    let temp = Tensor::constant(self);
+                    &temp + rhs
+                }
+            }
 
-    fn sub(self, rhs: &Tensor) -> Self::Output {
-        self + &(-rhs)
+            impl Mul<Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn mul(self, rhs: Tensor<$t>) -> Self::Output {
+                    let temp = Tensor::constant(self);
+                    temp * rhs
+                }
+            }
+
+            impl Mul<&Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn mul(self, rhs: &Tensor<$t>) -> Self::Output {
+                    let temp = Tensor::constant(self);
+                    temp * rhs
+                }
+            }
+
+            impl Sub<&Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn sub(self, rhs: &Tensor<$t>) -> Self::Output {
+                    self + &(-rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs!(f32, f64);
+
+// Tensor::pow의 지수
+pub trait Exponent<T: Float> {
+    fn pow_of(self, base: &Tensor<T>) -> Tensor<T>;
+}
+
+impl<T: Float> Exponent<T> for T {
+    fn pow_of(self, base: &Tensor<T>) -> Tensor<T> {
+        base.pow_const(self)
+    }
+}
+
+impl<T: Float> Exponent<T> for &Tensor<T> {
+    fn pow_of(self, base: &Tensor<T>) -> Tensor<T> {
+        base.pow_tensor(self)
+    }
+}
+
+impl<T: Float> Exponent<T> for Tensor<T> {
+    fn pow_of(self, base: &Tensor<T>) -> Tensor<T> {
+        base.pow_tensor(&self)
     }
 }
 
-impl Sum for Tensor {
+impl<T: Float> Sum for Tensor<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Tensor::new(0.0), |acc, x| &acc + &x)
+        iter.fold(Tensor::constant(T::zero()), |acc, x| &acc + &x)
     }
 }
 
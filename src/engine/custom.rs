@@ -0,0 +1,53 @@
+use std::{iter::zip, rc::Rc};
+
+use super::{Float, Operation, Tensor, is_grad_enabled};
+
+// engine.rs를 고치지 않고 추가하는 미분 가능한 연산
+// 입력 여러 개, 출력 하나인 스칼라 연산
+pub trait CustomOp<T: Float = f64> {
+    // to_dot, to_svg 에 표시되는 이름
+    fn name(&self) -> &str {
+        "custom"
+    }
+
+    fn forward(&self, inputs: &[T]) -> T;
+
+    // 출력 기울기 out_grad 를 받아서 입력 순서대로 입력 기울기를 반환
+    // 값만 반환하므로 이 연산을 지나는 backward_create_graph (2차 미분)는 지원하지 않음
+    fn backward(&self, inputs: &[T], output: T, out_grad: T) -> Vec<T>;
+}
+
+impl<T: Float> Tensor<T> {
+    // inputs 를 부모로 하는 노드 하나를 만들어 op 를 적용
+    pub fn apply(op: impl CustomOp<T> + 'static, inputs: &[Tensor<T>]) -> Tensor<T> {
+        fn _backward<T: Float>(out: &Tensor<T>) {
+            let op = out.custom_op().unwrap();
+            let prev = out.prev();
+            let inputs: Vec<T> = prev.iter().map(|p| p.data()).collect();
+            let grads = op.backward(&inputs, out.data(), out.grad());
+            assert_eq!(
+                grads.len(),
+                prev.len(),
+                "{}: backward must return one gradient per input",
+                op.name()
+            );
+
+            for (p, g) in zip(&prev, grads) {
+                p.set_grad(p.grad() + g);
+            }
+        }
+
+        let data: Vec<T> = inputs.iter().map(|t| t.data()).collect();
+        let tensor = Tensor::new_with_operation(
+            op.forward(&data),
+            Operation::Custom,
+            inputs.to_vec(),
+            _backward,
+        );
+        // no_grad 안에서는 기록하지 않으므로 연산을 보관할 필요가 없음
+        if is_grad_enabled() {
+            tensor.0.borrow_mut()._custom = Some(Rc::new(op));
+        }
+        tensor
+    }
+}
@@ -0,0 +1,196 @@
+use std::{collections::HashMap, fmt::Write};
+
+use super::{Float, Operation, Tensor};
+
+// SVG 배치용 크기 (px)
+const BOX_WIDTH: f64 = 260.0;
+const BOX_HEIGHT: f64 = 36.0;
+const OP_RADIUS: f64 = 22.0;
+const COLUMN_GAP: f64 = 90.0;
+const ROW_GAP: f64 = 24.0;
+const MARGIN: f64 = 20.0;
+
+impl<T: Float> Tensor<T> {
+    // micrograd의 draw_dot과 같은 형태의 Graphviz DOT 문서
+    // 값 노드는 record, 연산은 별도의 원형 노드로 그림
+    // `dot -Tsvg graph.dot > graph.svg` 로 렌더링
+    #[allow(clippy::mutable_key_type)]
+    pub fn to_dot(&self) -> String {
+        let nodes = self.topological_sort();
+        let ids = node_ids(&nodes);
+
+        let mut dot = String::from("digraph {\n    rankdir=LR;\n");
+        for (i, node) in nodes.iter().enumerate() {
+            writeln!(
+                dot,
+                "    n{} [shape=record, label=\"{{ {} | data {:.4} | grad {:.4} }}\"];",
+                i,
+                escape_dot(&node.label()),
+                node.data(),
+                node.grad()
+            )
+            .unwrap();
+
+            let op = node.operation();
+            if op != Operation::None {
+                writeln!(
+                    dot,
+                    "    n{}_op [label=\"{}\"];",
+                    i,
+                    escape_dot(&node.op_label())
+                )
+                .unwrap();
+                writeln!(dot, "    n{}_op -> n{};", i, i).unwrap();
+                for p in node.prev() {
+                    writeln!(dot, "    n{} -> n{}_op;", ids[&p], i).unwrap();
+                }
+            }
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    // dot 실행 파일 없이 바로 볼 수 있는 SVG
+    // leaf에서의 최장 거리로 열을 정하고, 같은 열 안에서는 위상 정렬 순서대로 쌓음
+    #[allow(clippy::mutable_key_type)]
+    pub fn to_svg(&self) -> String {
+        let nodes = self.topological_sort();
+        let ids = node_ids(&nodes);
+
+        let mut columns = vec![0; nodes.len()];
+        let mut rows = Vec::with_capacity(nodes.len());
+        let mut column_sizes: Vec<usize> = Vec::new();
+        for (i, node) in nodes.iter().enumerate() {
+            columns[i] = node
+                .prev()
+                .iter()
+                .map(|p| columns[ids[p]] + 1)
+                .max()
+                .unwrap_or(0);
+            if column_sizes.len() <= columns[i] {
+                column_sizes.resize(columns[i] + 1, 0);
+            }
+            rows.push(column_sizes[columns[i]]);
+            column_sizes[columns[i]] += 1;
+        }
+
+        let column_width = BOX_WIDTH + COLUMN_GAP;
+        let row_height = BOX_HEIGHT + ROW_GAP;
+        let position = |i: usize| {
+            (
+                MARGIN + columns[i] as f64 * column_width + COLUMN_GAP,
+                MARGIN + rows[i] as f64 * row_height,
+            )
+        };
+
+        let width = MARGIN * 2.0 + column_sizes.len() as f64 * column_width;
+        let height =
+            MARGIN * 2.0 + column_sizes.iter().max().copied().unwrap_or(0) as f64 * row_height;
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"12\">",
+            w = width,
+            h = height
+        )
+        .unwrap();
+        svg.push_str(
+            "  <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\"><path d=\"M0,0 L10,5 L0,10 z\"/></marker></defs>\n",
+        );
+
+        for (i, node) in nodes.iter().enumerate() {
+            let (x, y) = position(i);
+            let cy = y + BOX_HEIGHT / 2.0;
+
+            let op = node.operation();
+            if op != Operation::None {
+                let cx = x - COLUMN_GAP / 2.0;
+                for p in node.prev() {
+                    let (px, py) = position(ids[&p]);
+                    writeln!(
+                        svg,
+                        "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+                        px + BOX_WIDTH,
+                        py + BOX_HEIGHT / 2.0,
+                        cx - OP_RADIUS,
+                        cy
+                    )
+                    .unwrap();
+                }
+                writeln!(
+                    svg,
+                    "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"white\" stroke=\"black\"/>",
+                    cx, cy, OP_RADIUS
+                )
+                .unwrap();
+                writeln!(
+                    svg,
+                    "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>",
+                    cx,
+                    cy,
+                    escape_xml(&node.op_label())
+                )
+                .unwrap();
+                writeln!(
+                    svg,
+                    "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+                    cx + OP_RADIUS,
+                    cy,
+                    x,
+                    cy
+                )
+                .unwrap();
+            }
+
+            writeln!(
+                svg,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"white\" stroke=\"black\"/>",
+                x, y, BOX_WIDTH, BOX_HEIGHT
+            )
+            .unwrap();
+            writeln!(
+                svg,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{} | data {:.4} | grad {:.4}</text>",
+                x + BOX_WIDTH / 2.0,
+                cy,
+                escape_xml(&node.label()),
+                node.data(),
+                node.grad()
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+}
+
+// 위상 정렬 순서를 노드 번호로 사용
+#[allow(clippy::mutable_key_type)]
+fn node_ids<T: Float>(nodes: &[Tensor<T>]) -> HashMap<Tensor<T>, usize> {
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.clone(), i))
+        .collect()
+}
+
+fn escape_dot(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if matches!(c, '"' | '\\' | '{' | '}' | '|' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
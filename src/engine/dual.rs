@@ -0,0 +1,175 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// forward-mode 자동 미분용 dual number: value + tangent * ε (ε^2 = 0)
+// 연산을 할 때마다 값과 방향 미분(tangent)을 함께 계산하므로 backward가 필요 없음
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Dual {
+    pub value: f64,
+    pub tangent: f64,
+}
+
+impl Dual {
+    pub fn new(value: f64, tangent: f64) -> Self {
+        Dual { value, tangent }
+    }
+
+    // 미분 대상 변수 (tangent = 1)
+    pub fn variable(value: f64) -> Self {
+        Dual::new(value, 1.0)
+    }
+
+    // 상수 (tangent = 0)
+    pub fn constant(value: f64) -> Self {
+        Dual::new(value, 0.0)
+    }
+
+    // f(x + x'ε) = f(x) + f'(x) x' ε
+    fn chain(self, value: f64, derivative: f64) -> Dual {
+        Dual::new(value, derivative * self.tangent)
+    }
+
+    pub fn pow(self, rhs: f64) -> Dual {
+        self.chain(self.value.powf(rhs), rhs * self.value.powf(rhs - 1.0))
+    }
+
+    // Tensor::pow(&Tensor)와 같이 x <= 0 에서는 지수 쪽 미분을 0으로 둔다
+    pub fn pow_dual(self, rhs: Dual) -> Dual {
+        let value = self.value.powf(rhs.value);
+        let dx = rhs.value * self.value.powf(rhs.value - 1.0) * self.tangent;
+        let dy = if self.value > 0.0 {
+            value * self.value.ln() * rhs.tangent
+        } else {
+            0.0
+        };
+        Dual::new(value, dx + dy)
+    }
+
+    pub fn exp(self) -> Dual {
+        let e = self.value.exp();
+        self.chain(e, e)
+    }
+
+    pub fn tanh(self) -> Dual {
+        let t = self.value.tanh();
+        self.chain(t, 1.0 - t * t)
+    }
+
+    pub fn relu(self) -> Dual {
+        let slope = if self.value > 0.0 { 1.0 } else { 0.0 };
+        self.chain(self.value.max(0.0), slope)
+    }
+
+    pub fn sigmoid(self) -> Dual {
+        let s = 1.0 / (1.0 + (-self.value).exp());
+        self.chain(s, s * (1.0 - s))
+    }
+
+    pub fn log(self) -> Dual {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    pub fn sqrt(self) -> Dual {
+        let r = self.value.sqrt();
+        self.chain(r, 0.5 / r)
+    }
+
+    pub fn sin(self) -> Dual {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Dual {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+}
+
+// 입력 x에서 방향 v로의 Jacobian-vector product
+// 출력값과 J·v를 함께 반환
+pub fn jvp<F>(f: F, x: &[f64], v: &[f64]) -> (Vec<f64>, Vec<f64>)
+where
+    F: Fn(&[Dual]) -> Vec<Dual>,
+{
+    assert_eq!(x.len(), v.len(), "x and v must have the same length");
+    let inputs: Vec<Dual> = x.iter().zip(v).map(|(x, v)| Dual::new(*x, *v)).collect();
+
+    f(&inputs)
+        .into_iter()
+        .map(|out| (out.value, out.tangent))
+        .unzip()
+}
+
+impl From<f64> for Dual {
+    fn from(value: f64) -> Self {
+        Dual::constant(value)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.value + rhs.value, self.tangent + rhs.tangent)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.value * rhs.value,
+            self.tangent * rhs.value + self.value * rhs.tangent,
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.value, -self.tangent)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.value / rhs.value,
+            (self.tangent * rhs.value - self.value * rhs.tangent) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+// f64와의 연산 (양쪽 모두)
+macro_rules! impl_scalar_ops {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait<f64> for Dual {
+                type Output = Dual;
+
+                fn $method(self, rhs: f64) -> Self::Output {
+                    self.$method(Dual::constant(rhs))
+                }
+            }
+
+            impl $trait<Dual> for f64 {
+                type Output = Dual;
+
+                fn $method(self, rhs: Dual) -> Self::Output {
+                    Dual::constant(self).$method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_ops!(Add add, Sub sub, Mul mul, Div div);
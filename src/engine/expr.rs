@@ -0,0 +1,605 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{Float, Operation, Tensor, rules};
+
+// 인자가 하나인 내장 함수
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Func {
+    Tanh,
+    Exp,
+    Log,
+    Sqrt,
+    Abs,
+    Sin,
+    Cos,
+    Sigmoid,
+    Relu,
+    LeakyRelu(f64),
+    Softplus,
+    Gelu,
+    Silu,
+    Elu(f64),
+    // x > 0 이면 1, 아니면 0 (relu 등의 미분)
+    Step,
+    // x = 0 에서는 0
+    Sign,
+    Clamp(f64, f64),
+    // x > 0 이면 1, x = 0 이면 0.5 (max, min의 미분)
+    Heaviside,
+    // lo < x < hi 이면 1, 경계에서는 0.5 (clamp의 미분)
+    Indicator(f64, f64),
+}
+
+impl Func {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Func::Tanh => "tanh",
+            Func::Exp => "exp",
+            Func::Log => "log",
+            Func::Sqrt => "sqrt",
+            Func::Abs => "abs",
+            Func::Sin => "sin",
+            Func::Cos => "cos",
+            Func::Sigmoid => "sigmoid",
+            Func::Relu => "relu",
+            Func::LeakyRelu(_) => "leaky_relu",
+            Func::Softplus => "softplus",
+            Func::Gelu => "gelu",
+            Func::Silu => "silu",
+            Func::Elu(_) => "elu",
+            Func::Step => "step",
+            Func::Sign => "sign",
+            Func::Clamp(..) => "clamp",
+            Func::Heaviside => "heaviside",
+            Func::Indicator(..) => "indicator",
+        }
+    }
+
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Func::Step => rules::step(x),
+            Func::Sign => rules::sign(x),
+            Func::Heaviside => rules::max_share(x, 0.0),
+            Func::Indicator(lo, hi) => rules::indicator(x, *lo, *hi),
+            f => rules::value(f.operation(), &[x]),
+        }
+    }
+
+    // 같은 계산을 하는 Tensor 연산 (미분에만 쓰이는 Step 등은 제외)
+    fn operation(&self) -> Operation {
+        match *self {
+            Func::Tanh => Operation::Tanh,
+            Func::Exp => Operation::Exp,
+            Func::Log => Operation::Log,
+            Func::Sqrt => Operation::Sqrt,
+            Func::Abs => Operation::Abs,
+            Func::Sin => Operation::Sin,
+            Func::Cos => Operation::Cos,
+            Func::Sigmoid => Operation::Sigmoid,
+            Func::Relu => Operation::Relu,
+            Func::LeakyRelu(alpha) => Operation::LeakyRelu(alpha),
+            Func::Softplus => Operation::Softplus,
+            Func::Gelu => Operation::Gelu,
+            Func::Silu => Operation::Silu,
+            Func::Elu(alpha) => Operation::Elu(alpha),
+            Func::Clamp(lo, hi) => Operation::Clamp(lo, hi),
+            Func::Step | Func::Sign | Func::Heaviside | Func::Indicator(..) => unreachable!(),
+        }
+    }
+
+    fn latex(&self, arg: &str) -> String {
+        match self {
+            Func::Tanh | Func::Sin | Func::Cos => {
+                format!("\\{}\\left({}\\right)", self.name(), arg)
+            }
+            Func::Exp => format!("e^{{{}}}", arg),
+            Func::Log => format!("\\ln\\left({}\\right)", arg),
+            Func::Sqrt => format!("\\sqrt{{{}}}", arg),
+            Func::Abs => format!("\\left|{}\\right|", arg),
+            Func::Sigmoid => format!("\\sigma\\left({}\\right)", arg),
+            Func::LeakyRelu(alpha) | Func::Elu(alpha) => format!(
+                "\\operatorname{{{}}}_{{{}}}\\left({}\\right)",
+                self.name().replace('_', "\\_"),
+                alpha,
+                arg
+            ),
+            Func::Clamp(lo, hi) => format!(
+                "\\operatorname{{clamp}}_{{[{}, {}]}}\\left({}\\right)",
+                lo, hi, arg
+            ),
+            Func::Heaviside => format!("H\\left({}\\right)", arg),
+            Func::Indicator(lo, hi) => {
+                format!("\\mathbb{{1}}_{{[{}, {}]}}\\left({}\\right)", lo, hi, arg)
+            }
+            _ => format!("\\operatorname{{{}}}\\left({}\\right)", self.name(), arg),
+        }
+    }
+}
+
+// 그래프를 펼친 수식 트리
+// 연산자(+, -, *, /, neg)와 pow, func 로 만들면 간단한 정리가 함께 적용됨
+// (상수 계산, 0 / 1 제거, x * y^-1 -> x / y 등)
+#[derive(Clone, PartialEq, Debug)]
+pub enum Expr {
+    Const(f64),
+    Var(String),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    Func(Func, Box<Expr>),
+    Max(Box<Expr>, Box<Expr>),
+    Min(Box<Expr>, Box<Expr>),
+    // cond가 0이 아니면 a, 아니면 b
+    Where(Box<Expr>, Box<Expr>, Box<Expr>),
+    // CustomOp 처럼 식을 모르는 함수
+    Call(String, Vec<Expr>),
+}
+
+impl Expr {
+    pub fn var(name: &str) -> Expr {
+        Expr::Var(name.into())
+    }
+
+    pub fn pow(self, exponent: Expr) -> Expr {
+        match (self, exponent) {
+            (_, Expr::Const(0.0)) => Expr::Const(1.0),
+            (base, Expr::Const(1.0)) => base,
+            (Expr::Const(b), Expr::Const(e)) => Expr::Const(b.powf(e)),
+            (base, Expr::Const(-1.0)) => Expr::Const(1.0) / base,
+            (base, exponent) => Expr::Pow(Box::new(base), Box::new(exponent)),
+        }
+    }
+
+    pub fn func(f: Func, x: Expr) -> Expr {
+        match x {
+            Expr::Const(c) => Expr::Const(f.apply(c)),
+            x => Expr::Func(f, Box::new(x)),
+        }
+    }
+
+    pub fn max(self, other: Expr) -> Expr {
+        match (self, other) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a.max(b)),
+            (a, b) if a == b => a,
+            (a, b) => Expr::Max(Box::new(a), Box::new(b)),
+        }
+    }
+
+    pub fn min(self, other: Expr) -> Expr {
+        match (self, other) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a.min(b)),
+            (a, b) if a == b => a,
+            (a, b) => Expr::Min(Box::new(a), Box::new(b)),
+        }
+    }
+
+    pub fn select(cond: Expr, a: Expr, b: Expr) -> Expr {
+        match cond {
+            Expr::Const(c) if c != 0.0 => a,
+            Expr::Const(_) => b,
+            _ if a == b => a,
+            cond => Expr::Where(Box::new(cond), Box::new(a), Box::new(b)),
+        }
+    }
+
+    fn is_const(&self, value: f64) -> bool {
+        matches!(self, Expr::Const(c) if *c == value)
+    }
+
+    // 변수 값을 넣어서 계산
+    pub fn eval(&self, vars: &HashMap<String, f64>) -> f64 {
+        match self {
+            Expr::Const(c) => *c,
+            Expr::Var(name) => *vars
+                .get(name)
+                .unwrap_or_else(|| panic!("unbound variable {}", name)),
+            Expr::Neg(e) => -e.eval(vars),
+            Expr::Add(a, b) => a.eval(vars) + b.eval(vars),
+            Expr::Sub(a, b) => a.eval(vars) - b.eval(vars),
+            Expr::Mul(a, b) => a.eval(vars) * b.eval(vars),
+            Expr::Div(a, b) => a.eval(vars) / b.eval(vars),
+            Expr::Pow(a, b) => a.eval(vars).powf(b.eval(vars)),
+            Expr::Func(f, e) => f.apply(e.eval(vars)),
+            Expr::Max(a, b) => a.eval(vars).max(b.eval(vars)),
+            Expr::Min(a, b) => a.eval(vars).min(b.eval(vars)),
+            Expr::Where(cond, a, b) => {
+                if cond.eval(vars) != 0.0 {
+                    a.eval(vars)
+                } else {
+                    b.eval(vars)
+                }
+            }
+            Expr::Call(name, _) => panic!("cannot evaluate custom op {}", name),
+        }
+    }
+
+    // 괄호를 정하기 위한 우선순위 (클수록 강하게 묶임)
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) => 2,
+            Expr::Neg(_) => 3,
+            Expr::Const(c) if *c < 0.0 => 3,
+            Expr::Pow(..) => 4,
+            _ => 5,
+        }
+    }
+
+    fn fmt_prec(&self, f: &mut std::fmt::Formatter<'_>, min: u8) -> std::fmt::Result {
+        if self.precedence() < min {
+            write!(f, "(")?;
+            self.fmt_inner(f)?;
+            write!(f, ")")
+        } else {
+            self.fmt_inner(f)
+        }
+    }
+
+    fn fmt_inner(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Const(c) => write!(f, "{}", c),
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Neg(e) => {
+                write!(f, "-")?;
+                e.fmt_prec(f, 3)
+            }
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+                let (op, left, right) = match self {
+                    Expr::Add(..) => ("+", 1, 1),
+                    Expr::Sub(..) => ("-", 1, 2),
+                    Expr::Mul(..) => ("*", 2, 3),
+                    _ => ("/", 2, 3),
+                };
+                a.fmt_prec(f, left)?;
+                write!(f, " {} ", op)?;
+                b.fmt_prec(f, right)
+            }
+            Expr::Pow(a, b) => {
+                a.fmt_prec(f, 5)?;
+                write!(f, "^")?;
+                b.fmt_prec(f, 5)
+            }
+            Expr::Func(func, e) => {
+                write!(f, "{}({}", func.name(), e)?;
+                match func {
+                    Func::LeakyRelu(alpha) | Func::Elu(alpha) => write!(f, ", {})", alpha),
+                    Func::Clamp(lo, hi) | Func::Indicator(lo, hi) => {
+                        write!(f, ", {}, {})", lo, hi)
+                    }
+                    _ => write!(f, ")"),
+                }
+            }
+            Expr::Max(a, b) => write_call(f, "max", &[a, b]),
+            Expr::Min(a, b) => write_call(f, "min", &[a, b]),
+            Expr::Where(cond, a, b) => write_call(f, "where", &[cond, a, b]),
+            Expr::Call(name, args) => write_call(f, name, &args.iter().collect::<Vec<_>>()),
+        }
+    }
+
+    pub fn to_latex(&self) -> String {
+        self.latex_prec(0)
+    }
+
+    fn latex_prec(&self, min: u8) -> String {
+        // \frac 나 함수 호출은 그 자체로 묶여 있음
+        let precedence = match self {
+            Expr::Div(..) => 5,
+            _ => self.precedence(),
+        };
+        let inner = match self {
+            Expr::Const(c) => c.to_string(),
+            Expr::Var(name) => latex_var(name),
+            Expr::Neg(e) => format!("-{}", e.latex_prec(3)),
+            Expr::Add(a, b) => format!("{} + {}", a.latex_prec(1), b.latex_prec(1)),
+            Expr::Sub(a, b) => format!("{} - {}", a.latex_prec(1), b.latex_prec(2)),
+            Expr::Mul(a, b) => format!("{} \\cdot {}", a.latex_prec(2), b.latex_prec(3)),
+            Expr::Div(a, b) => format!("\\frac{{{}}}{{{}}}", a.latex_prec(0), b.latex_prec(0)),
+            Expr::Pow(a, b) => format!("{{{}}}^{{{}}}", a.latex_prec(5), b.latex_prec(0)),
+            Expr::Func(func, e) => func.latex(&e.latex_prec(0)),
+            Expr::Max(a, b) | Expr::Min(a, b) => format!(
+                "\\{}\\left({}, {}\\right)",
+                if matches!(self, Expr::Max(..)) {
+                    "max"
+                } else {
+                    "min"
+                },
+                a.latex_prec(0),
+                b.latex_prec(0)
+            ),
+            Expr::Where(cond, a, b) => format!(
+                "\\begin{{cases}} {} & {} \\neq 0 \\\\ {} & \\text{{otherwise}} \\end{{cases}}",
+                a.latex_prec(0),
+                cond.latex_prec(0),
+                b.latex_prec(0)
+            ),
+            Expr::Call(name, args) => {
+                let args: Vec<String> = args.iter().map(|a| a.latex_prec(0)).collect();
+                format!(
+                    "\\operatorname{{{}}}\\left({}\\right)",
+                    name.replace('_', "\\_"),
+                    args.join(", ")
+                )
+            }
+        };
+        if precedence < min {
+            format!("\\left({}\\right)", inner)
+        } else {
+            inner
+        }
+    }
+}
+
+// sum(exp(x_j))
+fn sum_exp(n: usize, x: impl Fn(usize) -> Expr) -> Expr {
+    (0..n).fold(Expr::Const(0.0), |acc, j| acc + Expr::func(Func::Exp, x(j)))
+}
+
+fn write_call(f: &mut std::fmt::Formatter<'_>, name: &str, args: &[&Expr]) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", arg)?;
+    }
+    write!(f, ")")
+}
+
+// x1 -> x_{1}, 여러 글자 이름은 \mathrm
+fn latex_var(name: &str) -> String {
+    let split = name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (head, digits) = name.split_at(split);
+    let head = if head.chars().count() == 1 {
+        head.to_string()
+    } else {
+        format!("\\mathrm{{{}}}", head.replace('_', "\\_"))
+    };
+    if digits.is_empty() || split == 0 {
+        head
+    } else {
+        format!("{}_{{{}}}", head, digits)
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_inner(f)
+    }
+}
+
+impl Add for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a + b),
+            (Expr::Const(z), e) | (e, Expr::Const(z)) if z == 0.0 => e,
+            (a, Expr::Neg(b)) => a - *b,
+            (a, Expr::Const(c)) if c < 0.0 => a - Expr::Const(-c),
+            (a, b) => Expr::Add(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Sub for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a - b),
+            (e, Expr::Const(0.0)) => e,
+            (Expr::Const(0.0), e) => -e,
+            (a, b) if a == b => Expr::Const(0.0),
+            (a, Expr::Neg(b)) => a + *b,
+            (a, b) => Expr::Sub(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Mul for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Expr::Const(a), Expr::Const(b)) => Expr::Const(a * b),
+            (Expr::Const(z), _) | (_, Expr::Const(z)) if z == 0.0 => Expr::Const(0.0),
+            (Expr::Const(o), e) | (e, Expr::Const(o)) if o == 1.0 => e,
+            (Expr::Const(m), e) | (e, Expr::Const(m)) if m == -1.0 => -e,
+            // 상수는 왼쪽으로 모음
+            (e, Expr::Const(c)) => Expr::Const(c) * e,
+            (Expr::Const(a), Expr::Mul(b, e)) if matches!(*b, Expr::Const(_)) => {
+                let Expr::Const(b) = *b else { unreachable!() };
+                Expr::Const(a * b) * *e
+            }
+            (Expr::Neg(a), b) => -(*a * b),
+            (a, Expr::Neg(b)) => -(a * *b),
+            // x * (1 / y) -> x / y
+            (a, Expr::Div(one, b)) | (Expr::Div(one, b), a) if one.is_const(1.0) => a / *b,
+            (a, b) if a == b => a.pow(Expr::Const(2.0)),
+            (a, b) => Expr::Mul(Box::new(a), Box::new(b)),
+        }
+    }
+}
+
+impl Div for Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Expr::Const(a), Expr::Const(b)) if b != 0.0 => Expr::Const(a / b),
+            (e, Expr::Const(1.0)) => e,
+            (Expr::Const(0.0), _) => Expr::Const(0.0),
+            (a, b) if a == b => Expr::Const(1.0),
+            (Expr::Neg(a), b) => -(*a / b),
+            (a, b) => match cancel(&a, &b) {
+                Some(e) => e,
+                None => Expr::Div(Box::new(a), Box::new(b)),
+            },
+        }
+    }
+}
+
+// 분자와 분모에 같은 인수가 있으면 하나씩 약분 (x * y / y -> x), 약분할 것이 없으면 None
+fn cancel(num: &Expr, den: &Expr) -> Option<Expr> {
+    let (mut num, mut den) = (factors(num), factors(den));
+    let before = den.len();
+    den.retain(|d| match num.iter().position(|n| n == d) {
+        Some(i) => {
+            num.remove(i);
+            false
+        }
+        None => true,
+    });
+    if den.len() == before {
+        return None;
+    }
+    let product = |fs: Vec<&Expr>| {
+        fs.into_iter()
+            .fold(Expr::Const(1.0), |acc, f| acc * f.clone())
+    };
+    Some(product(num) / product(den))
+}
+
+// 곱셈으로 이어진 인수들
+fn factors(e: &Expr) -> Vec<&Expr> {
+    match e {
+        Expr::Mul(a, b) => [factors(a), factors(b)].concat(),
+        e => vec![e],
+    }
+}
+
+impl Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Expr::Const(c) => Expr::Const(-c),
+            Expr::Neg(e) => *e,
+            Expr::Sub(a, b) => Expr::Sub(b, a),
+            e => Expr::Neg(Box::new(e)),
+        }
+    }
+}
+
+// 교육용이므로 공유되는 부분식도 모두 펼침 (큰 그래프에서는 식이 매우 길어짐)
+impl<T: Float> Tensor<T> {
+    // label이 있는 leaf는 변수, 없는 leaf는 값 그대로 상수로 표시
+    #[allow(clippy::mutable_key_type)]
+    pub fn to_expr(&self) -> Expr {
+        let mut exprs = HashMap::new();
+        for node in self.topological_sort() {
+            let e = node.expr_of(&exprs);
+            exprs.insert(node, e);
+        }
+        exprs.remove(self).unwrap()
+    }
+
+    // d self / d wrt 를 정리한 식 (wrt 이외의 경로는 고정한 편미분, backward의 wrt.grad()와 같음)
+    #[allow(clippy::mutable_key_type)]
+    pub fn symbolic_grad(&self, wrt: &Tensor<T>) -> Expr {
+        let mut exprs = HashMap::new();
+        let mut grads = HashMap::new();
+        for node in self.topological_sort() {
+            let e = node.expr_of(&exprs);
+            let d = if node == *wrt {
+                Expr::Const(1.0)
+            } else {
+                node.grad_expr_of(&e, &exprs, &grads)
+            };
+            exprs.insert(node.clone(), e);
+            grads.insert(node, d);
+        }
+        grads.remove(self).unwrap()
+    }
+
+    #[allow(clippy::mutable_key_type)]
+    fn expr_of(&self, exprs: &HashMap<Tensor<T>, Expr>) -> Expr {
+        let (operation, prev) = self.scalar_view();
+        let arg = |i: usize| exprs[&prev[i]].clone();
+        let func = |f: Func| Expr::func(f, arg(0));
+
+        match operation {
+            Operation::None => {
+                let label = self.label();
+                if label.is_empty() {
+                    Expr::Const(self.data().to_f64())
+                } else {
+                    Expr::Var(label)
+                }
+            }
+            Operation::Neg => -arg(0),
+            Operation::Add => arg(0) + arg(1),
+            Operation::Mul => arg(0) * arg(1),
+            // Program 안에서만 쓰이는 연산
+            Operation::Div => unreachable!(),
+            Operation::Pow => arg(0).pow(arg(1)),
+            Operation::Powf(n) => arg(0).pow(Expr::Const(n)),
+            Operation::Tanh => func(Func::Tanh),
+            Operation::Exp => func(Func::Exp),
+            Operation::Relu => func(Func::Relu),
+            Operation::LeakyRelu(alpha) => func(Func::LeakyRelu(alpha)),
+            Operation::Sigmoid => func(Func::Sigmoid),
+            Operation::Log => func(Func::Log),
+            Operation::Sqrt => func(Func::Sqrt),
+            Operation::Abs => func(Func::Abs),
+            Operation::Sin => func(Func::Sin),
+            Operation::Cos => func(Func::Cos),
+            Operation::Softplus => func(Func::Softplus),
+            Operation::Gelu => func(Func::Gelu),
+            Operation::Silu => func(Func::Silu),
+            Operation::Elu(alpha) => func(Func::Elu(alpha)),
+            Operation::Dot => {
+                let n = prev.len() / 2;
+                (0..n).fold(Expr::Const(0.0), |acc, i| acc + arg(i) * arg(n + i))
+            }
+            Operation::Max => arg(0).max(arg(1)),
+            Operation::Min => arg(0).min(arg(1)),
+            Operation::Clamp(lo, hi) => func(Func::Clamp(lo, hi)),
+            Operation::Where => Expr::select(arg(0), arg(1), arg(2)),
+            // 최댓값을 빼는 것은 계산 방법일 뿐이므로 식은 정의대로 펼침
+            Operation::Softmax => Expr::func(Func::Exp, arg(0) - arg(1)),
+            Operation::LogSoftmax => arg(0) - arg(1),
+            Operation::LogSumExp => Expr::func(Func::Log, sum_exp(prev.len(), arg)),
+            Operation::Custom => Expr::Call(self.op_label(), (0..prev.len()).map(arg).collect()),
+            // 출력 식은 행과 x의 dot으로 펼치므로 공유 노드의 식은 쓰이지 않음
+            Operation::MatVec(_) => Expr::Const(0.0),
+            Operation::Row(_) => unreachable!(),
+        }
+    }
+
+    // 연쇄 법칙: 부모의 식과 미분으로 이 노드의 미분
+    #[allow(clippy::mutable_key_type)]
+    fn grad_expr_of(
+        &self,
+        out: &Expr,
+        exprs: &HashMap<Tensor<T>, Expr>,
+        grads: &HashMap<Tensor<T>, Expr>,
+    ) -> Expr {
+        let (operation, prev) = self.scalar_view();
+        let x: Vec<Expr> = prev.iter().map(|p| exprs[p].clone()).collect();
+        let mut locals = Vec::with_capacity(prev.len());
+        match operation {
+            Operation::MatVec(_) => return Expr::Const(0.0),
+            Operation::Custom => {
+                let name = self.op_label();
+                locals.extend(
+                    (0..prev.len()).map(|i| Expr::Call(format!("d{}_{}", i, name), x.clone())),
+                );
+            }
+            operation => rules::partials(operation, &x, out, &mut locals),
+        }
+        locals
+            .into_iter()
+            .zip(&prev)
+            .fold(Expr::Const(0.0), |acc, (local, p)| {
+                acc + local * grads[p].clone()
+            })
+    }
+}
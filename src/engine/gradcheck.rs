@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use super::{Float, Tensor, jacobian::SavedGrads};
+
+// 입력 하나에 대한 비교 결과
+#[derive(Clone, Debug)]
+pub struct GradCheckEntry {
+    pub index: usize,
+    pub label: String,
+    pub analytic: f64,
+    pub numeric: f64,
+    pub abs_error: f64,
+    pub rel_error: f64,
+    pub passed: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct GradCheckReport {
+    pub entries: Vec<GradCheckEntry>,
+}
+
+impl GradCheckReport {
+    pub fn passed(&self) -> bool {
+        self.entries.iter().all(|e| e.passed)
+    }
+
+    pub fn mismatches(&self) -> Vec<&GradCheckEntry> {
+        self.entries.iter().filter(|e| !e.passed).collect()
+    }
+}
+
+impl Display for GradCheckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for e in &self.entries {
+            writeln!(
+                f,
+                "[{}] {} | analytic: {:.6e} | numeric: {:.6e} | abs: {:.2e} | rel: {:.2e} | {}",
+                e.index,
+                e.label,
+                e.analytic,
+                e.numeric,
+                e.abs_error,
+                e.rel_error,
+                if e.passed { "ok" } else { "MISMATCH" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// f가 inputs로 만든 그래프를 backward 해서 얻은 기울기와
+// 중앙 차분 (f(x + eps) - f(x - eps)) / 2eps 를 입력마다 비교
+// 절대 오차나 상대 오차 중 하나라도 tol 이하면 통과
+// inputs의 data와, f가 잡아 둔 파라미터까지 포함한 그래프 전체의 grad는 검사 후 원래대로 돌려놓음
+// f32 Tensor도 검사할 수 있지만 차분 자체의 오차가 크므로 eps, tol을 크게 잡아야 함
+pub fn gradcheck<T, F>(f: F, inputs: &[Tensor<T>], eps: f64, tol: f64) -> GradCheckReport
+where
+    T: Float,
+    F: Fn(&[Tensor<T>]) -> Tensor<T>,
+{
+    let output = f(inputs);
+    let saved = SavedGrads::new(std::slice::from_ref(&output), inputs);
+    saved.zero();
+    output.backward();
+    let analytic: Vec<f64> = inputs.iter().map(|x| x.grad().to_f64()).collect();
+
+    let entries = inputs
+        .iter()
+        .enumerate()
+        .map(|(index, x)| {
+            // f32에서는 x ± eps가 반올림되므로 실제로 움직인 거리로 나눔
+            let data = x.data();
+            let (lo, hi) = (
+                T::from_f64(data.to_f64() - eps),
+                T::from_f64(data.to_f64() + eps),
+            );
+            x.set_data(hi);
+            let plus = f(inputs).data().to_f64();
+            x.set_data(lo);
+            let minus = f(inputs).data().to_f64();
+            x.set_data(data);
+
+            let numeric = (plus - minus) / (hi.to_f64() - lo.to_f64());
+            let abs_error = (analytic[index] - numeric).abs();
+            let scale = analytic[index].abs().max(numeric.abs());
+            let rel_error = if scale > 0.0 { abs_error / scale } else { 0.0 };
+
+            GradCheckEntry {
+                index,
+                label: x.label(),
+                analytic: analytic[index],
+                numeric,
+                abs_error,
+                rel_error,
+                passed: abs_error <= tol || rel_error <= tol,
+            }
+        })
+        .collect();
+
+    saved.restore();
+
+    GradCheckReport { entries }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use super::{Float, Tensor};
+
+// J[i][j] = d outputs[i] / d inputs[j]
+// 출력마다 같은 그래프로 backward를 한 번씩 하고 (그래프는 유지), 그 전에 기울기를 모두 0으로 만듦
+// 그래프 안의 노드와 inputs의 기울기는 끝난 뒤 원래 값으로 돌려놓음 (파라미터 기울기가 섞이지 않음)
+pub fn jacobian<T: Float>(outputs: &[Tensor<T>], inputs: &[Tensor<T>]) -> Vec<Vec<T>> {
+    let saved = SavedGrads::new(outputs, inputs);
+    let rows = outputs
+        .iter()
+        .map(|output| {
+            saved.zero();
+            output.backward();
+            inputs.iter().map(|x| x.grad()).collect()
+        })
+        .collect();
+    saved.restore();
+    rows
+}
+
+// H[i][j] = d^2 f / d inputs[i] d inputs[j]
+// backward_create_graph로 기울기 그래프를 한 번 만든 뒤, 그 기울기들의 jacobian
+// CustomOp은 기울기를 값으로만 주므로 그래프에 있으면 기울기를 건드리기 전에 panic
+pub fn hessian<T: Float>(f: &Tensor<T>, inputs: &[Tensor<T>]) -> Vec<Vec<T>> {
+    let saved = SavedGrads::new(std::slice::from_ref(f), inputs);
+    if let Some(node) = saved.nodes.iter().find(|node| node.custom_op().is_some()) {
+        panic!("hessian does not support custom op {}", node.op_label());
+    }
+    let grads = f.backward_create_graph(inputs);
+    let rows = jacobian(&grads, inputs);
+    saved.restore();
+    rows
+}
+
+// roots의 그래프와 inputs의 기울기를 저장해 두었다가 되돌림
+pub(super) struct SavedGrads<T: Float> {
+    nodes: Vec<Tensor<T>>,
+    grads: Vec<T>,
+}
+
+impl<T: Float> SavedGrads<T> {
+    #[allow(clippy::mutable_key_type)]
+    pub(super) fn new(roots: &[Tensor<T>], inputs: &[Tensor<T>]) -> Self {
+        let mut nodes = Tensor::topological_sort_many(roots);
+        let mut seen: HashSet<Tensor<T>> = nodes.iter().cloned().collect();
+        for x in inputs {
+            if seen.insert(x.clone()) {
+                nodes.push(x.clone());
+            }
+        }
+        let grads = nodes.iter().map(|node| node.grad()).collect();
+        Self { nodes, grads }
+    }
+
+    pub(super) fn zero(&self) {
+        for node in &self.nodes {
+            node.set_grad(T::zero());
+        }
+    }
+
+    pub(super) fn restore(&self) {
+        for (node, grad) in self.nodes.iter().zip(&self.grads) {
+            node.set_grad(*grad);
+        }
+    }
+}
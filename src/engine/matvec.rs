@@ -0,0 +1,86 @@
+use std::iter::zip;
+
+use super::{Float, Operation, Tensor, nd::gemm, rules};
+
+impl<T: Float> Tensor<T> {
+    // 행렬 w (행마다 Vec)와 벡터 x의 곱, 출력은 행마다 하나
+    // 출력들이 공유하는 MatVec 노드 하나가 gemm으로 한꺼번에 계산하고, 출력 i는 그 노드만 가리킴
+    // backward도 출력 기울기 g를 모아서 한 번에 dw = g x^T, dx = w^T g
+    pub fn matvec(w: &[Vec<Tensor<T>>], x: &[Tensor<T>]) -> Vec<Tensor<T>> {
+        assert!(
+            w.iter().all(|row| row.len() == x.len()),
+            "matvec requires rows of the same length as x"
+        );
+        let (rows, n) = (w.len(), x.len());
+        let node = Tensor::matvec_node(rows, [w.concat(), x.to_vec()].concat());
+
+        let (w, x) = (values(&w.concat()), values(x));
+        let mut out = vec![T::zero(); rows];
+        gemm(&w, &x, &mut out, (rows, n, 1));
+
+        out.into_iter()
+            .enumerate()
+            .map(|(i, data)| {
+                Tensor::new_with_operation(
+                    data,
+                    Operation::Row(i),
+                    vec![node.clone()],
+                    _backward_row,
+                )
+            })
+            .collect()
+    }
+
+    // 출력 없이 공유 노드만 만듦 (JSON에서 다시 만들 때는 출력을 하나씩 붙임)
+    pub(super) fn matvec_node(rows: usize, prev: Vec<Tensor<T>>) -> Tensor<T> {
+        let node = Tensor::new_with_operation(T::zero(), Operation::MatVec(rows), prev, _backward);
+        node.0.borrow_mut()._row_grads = vec![T::zero(); rows];
+        node
+    }
+
+    // 공유 노드 node의 i번째 출력, 값은 i번째 행과 x의 dot
+    pub(super) fn matvec_row(node: &Tensor<T>, i: usize) -> Tensor<T> {
+        let out = Tensor::new_with_operation(
+            T::zero(),
+            Operation::Row(i),
+            vec![node.clone()],
+            _backward_row,
+        );
+        let (operation, prev) = out.scalar_view();
+        out.set_data(rules::value(operation, &values(&prev)));
+        out
+    }
+}
+
+// 출력 기울기를 공유 노드에 모아 둠 (공유 노드는 모든 출력보다 뒤에 처리됨)
+fn _backward_row<T: Float>(out: &Tensor<T>) {
+    let Operation::Row(i) = out.operation() else {
+        unreachable!()
+    };
+    let node = &out.prev()[0];
+    node.0.borrow_mut()._row_grads[i] += out.grad();
+}
+
+// 모은 출력 기울기 g로 dw = g x^T, dx = w^T g (x^T w 로 계산해서 전치를 만들지 않음)
+fn _backward<T: Float>(node: &Tensor<T>) {
+    let Operation::MatVec(rows) = node.operation() else {
+        unreachable!()
+    };
+    let g = std::mem::replace(&mut node.0.borrow_mut()._row_grads, vec![T::zero(); rows]);
+    let prev = node.prev();
+    let n = prev.len() / (rows + 1);
+    let (w, x) = prev.split_at(rows * n);
+
+    let mut dw = vec![T::zero(); rows * n];
+    gemm(&g, &values(x), &mut dw, (rows, 1, n));
+    let mut dx = vec![T::zero(); n];
+    gemm(&g, &values(w), &mut dx, (1, rows, n));
+
+    for (p, d) in zip(&prev, dw.into_iter().chain(dx)) {
+        p.set_grad(p.grad() + d);
+    }
+}
+
+fn values<T: Float>(xs: &[Tensor<T>]) -> Vec<T> {
+    xs.iter().map(|x| x.data()).collect()
+}
@@ -0,0 +1,917 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Div, Mul, Neg, Sub},
+    rc::Rc,
+};
+
+use super::{Float, is_grad_enabled};
+
+// n 차원 Tensor의 연산 종류
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum NdOperation {
+    None,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Powf(f64),
+    Exp,
+    Log,
+    Tanh,
+    Relu,
+    Sigmoid,
+    // 축을 없애는 reduction
+    Sum(usize),
+    Mean(usize),
+    Max(usize),
+    Reshape,
+    Transpose(usize, usize),
+    MatMul,
+    Dot,
+}
+
+// 스칼라 Tensor와 같은 구조에 값/기울기를 배열로, shape/stride를 함께 보관
+// 연산 결과는 항상 row-major로 연속된 배열
+struct NdTensorData<T: Float> {
+    data: Vec<T>,
+    grad: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    label: String,
+
+    _backward: Option<fn(&NdTensor<T>)>,
+    _prev: Vec<NdTensor<T>>,
+    _op: NdOperation,
+}
+
+// TensorData와 같은 이유로 반복문으로 해제
+impl<T: Float> Drop for NdTensorData<T> {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self._prev);
+        while let Some(tensor) = stack.pop() {
+            if let Ok(cell) = Rc::try_unwrap(tensor.0) {
+                stack.append(&mut cell.into_inner()._prev);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NdTensor<T: Float = f64>(Rc<RefCell<NdTensorData<T>>>);
+
+impl<T: Float> PartialEq for NdTensor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Float> Eq for NdTensor<T> {}
+
+impl<T: Float> Hash for NdTensor<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state);
+    }
+}
+
+// Tensor::new와 같이 리터럴만으로도 타입 추론이 되도록 f64 전용
+impl NdTensor {
+    pub fn new(data: Vec<f64>, shape: &[usize]) -> Self {
+        Self::leaf(data, shape)
+    }
+
+    pub fn new_with_label(data: Vec<f64>, shape: &[usize], label: &str) -> Self {
+        let tensor = Self::leaf(data, shape);
+        tensor.set_label(label);
+        tensor
+    }
+}
+
+impl<T: Float> NdTensor<T> {
+    pub fn leaf(data: Vec<T>, shape: &[usize]) -> Self {
+        assert_eq!(
+            data.len(),
+            shape.iter().product::<usize>(),
+            "data length does not match shape {:?}",
+            shape
+        );
+        let grad = vec![T::zero(); data.len()];
+        NdTensor(Rc::new(RefCell::new(NdTensorData {
+            data,
+            grad,
+            shape: shape.to_vec(),
+            strides: contiguous_strides(shape),
+            label: String::new(),
+            _backward: None,
+            _prev: vec![],
+            _op: NdOperation::None,
+        })))
+    }
+
+    // shape이 []인 0차원 tensor
+    pub fn scalar(value: T) -> Self {
+        Self::leaf(vec![value], &[])
+    }
+
+    pub fn zeros(shape: &[usize]) -> Self {
+        Self::leaf(vec![T::zero(); shape.iter().product()], shape)
+    }
+
+    pub fn ones(shape: &[usize]) -> Self {
+        Self::leaf(vec![T::one(); shape.iter().product()], shape)
+    }
+
+    // no_grad 안에서는 기록 없이 leaf로 만듦
+    fn new_with_operation(
+        data: Vec<T>,
+        shape: &[usize],
+        operation: NdOperation,
+        prev: Vec<NdTensor<T>>,
+        backward: fn(&NdTensor<T>),
+    ) -> Self {
+        let tensor = Self::leaf(data, shape);
+        if is_grad_enabled() {
+            let mut t = tensor.0.borrow_mut();
+            t._op = operation;
+            t._prev = prev;
+            t._backward = Some(backward);
+        }
+        tensor
+    }
+
+    // 데이터 조회
+    pub fn data(&self) -> Vec<T> {
+        self.0.borrow().data.clone()
+    }
+    pub fn grad(&self) -> Vec<T> {
+        self.0.borrow().grad.clone()
+    }
+    pub fn shape(&self) -> Vec<usize> {
+        self.0.borrow().shape.clone()
+    }
+    pub fn strides(&self) -> Vec<usize> {
+        self.0.borrow().strides.clone()
+    }
+    pub fn ndim(&self) -> usize {
+        self.0.borrow().shape.len()
+    }
+    pub fn len(&self) -> usize {
+        self.0.borrow().data.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn prev(&self) -> Vec<NdTensor<T>> {
+        self.0.borrow()._prev.clone()
+    }
+    pub fn label(&self) -> String {
+        self.0.borrow().label.clone()
+    }
+    pub fn is_leaf(&self) -> bool {
+        self.0.borrow()._prev.is_empty()
+    }
+
+    // 원소 하나 조회
+    pub fn get(&self, index: &[usize]) -> T {
+        let t = self.0.borrow();
+        t.data[flat_index(index, &t.shape, &t.strides)]
+    }
+
+    pub fn grad_at(&self, index: &[usize]) -> T {
+        let t = self.0.borrow();
+        t.grad[flat_index(index, &t.shape, &t.strides)]
+    }
+
+    // 원소가 하나인 tensor의 값
+    pub fn item(&self) -> T {
+        let t = self.0.borrow();
+        assert_eq!(t.data.len(), 1, "item() requires a single element");
+        t.data[0]
+    }
+
+    // setter
+    pub fn set_data(&self, data: Vec<T>) {
+        let mut t = self.0.borrow_mut();
+        assert_eq!(data.len(), t.data.len(), "data length does not match shape");
+        t.data = data;
+    }
+    pub fn set_label(&self, label: &str) {
+        self.0.borrow_mut().label = label.into();
+    }
+    pub fn zero_grad(&self) {
+        self.0.borrow_mut().grad.fill(T::zero());
+    }
+
+    fn add_grad(&self, index: usize, grad: T) {
+        self.0.borrow_mut().grad[index] += grad;
+    }
+
+    fn operation(&self) -> NdOperation {
+        self.0.borrow()._op
+    }
+
+    // root의 모든 원소에 대해 기울기 1로 시작 (즉 sum()의 기울기)
+    pub fn backward(&self) {
+        let mut todos = self.topological_sort();
+        todos.reverse();
+
+        for node in &todos {
+            if !node.is_leaf() {
+                node.zero_grad();
+            }
+        }
+        self.0.borrow_mut().grad.fill(T::one());
+
+        for node in &todos {
+            let backward = node.0.borrow()._backward;
+            if let Some(f) = backward {
+                f(node);
+            }
+        }
+    }
+
+    #[allow(clippy::mutable_key_type)]
+    pub fn topological_sort(&self) -> Vec<NdTensor<T>> {
+        let mut visited = HashSet::new();
+        let mut todo = Vec::new();
+
+        let mut stack = vec![(self.clone(), false)];
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                todo.push(node);
+                continue;
+            }
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+
+            let prev = node.prev();
+            stack.push((node, true));
+            for p in prev.into_iter().rev() {
+                if !visited.contains(&p) {
+                    stack.push((p, false));
+                }
+            }
+        }
+
+        todo
+    }
+
+    // ---- 원소별 연산
+
+    fn map(&self, operation: NdOperation, f: impl Fn(T) -> T, backward: fn(&NdTensor<T>)) -> Self {
+        let data = self.0.borrow().data.iter().map(|x| f(*x)).collect();
+        NdTensor::new_with_operation(data, &self.shape(), operation, vec![self.clone()], backward)
+    }
+
+    // 입력 x, 출력 y, 출력 기울기 g로 입력 기울기를 계산하는 원소별 backward
+    fn map_backward(out: &NdTensor<T>, local: impl Fn(T, T) -> T) {
+        let x = &out.prev()[0];
+        let (data, grad) = {
+            let o = out.0.borrow();
+            (o.data.clone(), o.grad.clone())
+        };
+        let mut input = x.0.borrow_mut();
+        for i in 0..data.len() {
+            let dx = local(input.data[i], data[i]) * grad[i];
+            input.grad[i] += dx;
+        }
+    }
+
+    pub fn exp(&self) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |_, y| y);
+        }
+
+        self.map(NdOperation::Exp, |x| x.exp(), _backward)
+    }
+
+    pub fn log(&self) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |x, _| T::one() / x);
+        }
+
+        self.map(NdOperation::Log, |x| x.ln(), _backward)
+    }
+
+    pub fn tanh(&self) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |_, y| T::one() - y * y);
+        }
+
+        self.map(NdOperation::Tanh, |x| x.tanh(), _backward)
+    }
+
+    pub fn relu(&self) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |x, _| if x > T::zero() { T::one() } else { T::zero() });
+        }
+
+        self.map(NdOperation::Relu, |x| x.max(T::zero()), _backward)
+    }
+
+    pub fn sigmoid(&self) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |_, y| y * (T::one() - y));
+        }
+
+        self.map(
+            NdOperation::Sigmoid,
+            |x| T::one() / (T::one() + (-x).exp()),
+            _backward,
+        )
+    }
+
+    pub fn pow(&self, rhs: T) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let NdOperation::Powf(n) = out.operation() else {
+                unreachable!()
+            };
+            let n = T::from_f64(n);
+            NdTensor::map_backward(out, |x, _| n * x.powf(n - T::one()));
+        }
+
+        self.map(NdOperation::Powf(rhs.to_f64()), |x| x.powf(rhs), _backward)
+    }
+
+    // ---- broadcasting 이항 연산
+
+    fn binary(
+        &self,
+        rhs: &NdTensor<T>,
+        operation: NdOperation,
+        f: impl Fn(T, T) -> T,
+        backward: fn(&NdTensor<T>),
+    ) -> Self {
+        let (a, b) = (self.0.borrow(), rhs.0.borrow());
+        let shape = broadcast_shape(&a.shape, &b.shape);
+        let a_index = offsets(&shape, &broadcast_strides(&a.shape, &a.strides, &shape));
+        let b_index = offsets(&shape, &broadcast_strides(&b.shape, &b.strides, &shape));
+        let data = a_index
+            .iter()
+            .zip(&b_index)
+            .map(|(i, j)| f(a.data[*i], b.data[*j]))
+            .collect();
+        drop((a, b));
+
+        NdTensor::new_with_operation(
+            data,
+            &shape,
+            operation,
+            vec![self.clone(), rhs.clone()],
+            backward,
+        )
+    }
+
+    // 출력 원소마다 (a, b, 출력 기울기) -> (da, db)
+    // broadcast 된 축의 기울기는 같은 입력 원소로 모두 더해짐
+    fn binary_backward(out: &NdTensor<T>, local: impl Fn(T, T) -> (T, T)) {
+        let prev = out.prev();
+        let (l, r) = (&prev[0], &prev[1]);
+        let (shape, grad) = {
+            let o = out.0.borrow();
+            (o.shape.clone(), o.grad.clone())
+        };
+        let (l_shape, l_strides, l_data) = {
+            let t = l.0.borrow();
+            (t.shape.clone(), t.strides.clone(), t.data.clone())
+        };
+        let (r_shape, r_strides, r_data) = {
+            let t = r.0.borrow();
+            (t.shape.clone(), t.strides.clone(), t.data.clone())
+        };
+        let l_index = offsets(&shape, &broadcast_strides(&l_shape, &l_strides, &shape));
+        let r_index = offsets(&shape, &broadcast_strides(&r_shape, &r_strides, &shape));
+
+        for k in 0..grad.len() {
+            let (i, j) = (l_index[k], r_index[k]);
+            let (da, db) = local(l_data[i], r_data[j]);
+            l.add_grad(i, da * grad[k]);
+            r.add_grad(j, db * grad[k]);
+        }
+    }
+
+    // ---- reduction
+
+    fn reduce(
+        &self,
+        axis: usize,
+        operation: NdOperation,
+        init: T,
+        f: impl Fn(T, T) -> T,
+        backward: fn(&NdTensor<T>),
+    ) -> Self {
+        let t = self.0.borrow();
+        assert!(axis < t.shape.len(), "axis {} out of range", axis);
+        let (out_shape, index) = reduce_index(&t.shape, axis);
+
+        let mut data = vec![init; out_shape.iter().product()];
+        for (i, x) in t.data.iter().enumerate() {
+            data[index[i]] = f(data[index[i]], *x);
+        }
+        drop(t);
+
+        NdTensor::new_with_operation(data, &out_shape, operation, vec![self.clone()], backward)
+    }
+
+    pub fn sum(&self, axis: usize) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let NdOperation::Sum(axis) = out.operation() else {
+                unreachable!()
+            };
+            let x = &out.prev()[0];
+            let (_, index) = reduce_index(&x.shape(), axis);
+            let grad = out.grad();
+            let mut input = x.0.borrow_mut();
+            for (i, g) in input.grad.iter_mut().enumerate() {
+                *g += grad[index[i]];
+            }
+        }
+
+        self.reduce(
+            axis,
+            NdOperation::Sum(axis),
+            T::zero(),
+            |a, b| a + b,
+            _backward,
+        )
+    }
+
+    pub fn mean(&self, axis: usize) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let NdOperation::Mean(axis) = out.operation() else {
+                unreachable!()
+            };
+            let x = &out.prev()[0];
+            let shape = x.shape();
+            let n = T::from_f64(shape[axis] as f64);
+            let (_, index) = reduce_index(&shape, axis);
+            let grad = out.grad();
+            let mut input = x.0.borrow_mut();
+            for (i, g) in input.grad.iter_mut().enumerate() {
+                *g += grad[index[i]] / n;
+            }
+        }
+
+        let n = T::from_f64(self.shape()[axis] as f64);
+        let sum = no_record(|| self.sum(axis));
+        let data = sum.data().into_iter().map(|x| x / n).collect();
+        NdTensor::new_with_operation(
+            data,
+            &sum.shape(),
+            NdOperation::Mean(axis),
+            vec![self.clone()],
+            _backward,
+        )
+    }
+
+    // 최댓값이 여러 개면 처음 나온 원소로만 기울기를 보냄
+    pub fn max(&self, axis: usize) -> Self {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let NdOperation::Max(axis) = out.operation() else {
+                unreachable!()
+            };
+            let x = &out.prev()[0];
+            let (_, index) = reduce_index(&x.shape(), axis);
+            let (data, grad) = (out.data(), out.grad());
+            let mut taken = vec![false; data.len()];
+            let mut input = x.0.borrow_mut();
+            for (i, k) in index.into_iter().enumerate() {
+                if !taken[k] && input.data[i] == data[k] {
+                    taken[k] = true;
+                    input.grad[i] += grad[k];
+                }
+            }
+        }
+
+        let init = self.data().into_iter().reduce(|a, b| a.min(b));
+        self.reduce(
+            axis,
+            NdOperation::Max(axis),
+            init.unwrap_or(T::zero()),
+            |a, b| a.max(b),
+            _backward,
+        )
+    }
+
+    // 모든 축을 줄인 0차원 결과
+    pub fn sum_all(&self) -> Self {
+        self.reshape(&[self.len()]).sum(0)
+    }
+
+    pub fn mean_all(&self) -> Self {
+        self.reshape(&[self.len()]).mean(0)
+    }
+
+    pub fn max_all(&self) -> Self {
+        self.reshape(&[self.len()]).max(0)
+    }
+
+    // ---- shape 변환
+
+    pub fn reshape(&self, shape: &[usize]) -> Self {
+        assert_eq!(
+            self.len(),
+            shape.iter().product::<usize>(),
+            "cannot reshape {:?} into {:?}",
+            self.shape(),
+            shape
+        );
+
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let x = &out.prev()[0];
+            let grad = out.grad();
+            let mut input = x.0.borrow_mut();
+            for (g, d) in input.grad.iter_mut().zip(grad) {
+                *g += d;
+            }
+        }
+
+        NdTensor::new_with_operation(
+            self.data(),
+            shape,
+            NdOperation::Reshape,
+            vec![self.clone()],
+            _backward,
+        )
+    }
+
+    // 두 축을 바꾼 결과를 연속된 배열로 만듦
+    pub fn transpose(&self, a: usize, b: usize) -> Self {
+        let (shape, strides, data) = {
+            let t = self.0.borrow();
+            (t.shape.clone(), t.strides.clone(), t.data.clone())
+        };
+        assert!(a < shape.len() && b < shape.len(), "axis out of range");
+
+        let (mut out_shape, mut view_strides) = (shape.clone(), strides);
+        out_shape.swap(a, b);
+        view_strides.swap(a, b);
+        let out = offsets(&out_shape, &view_strides)
+            .into_iter()
+            .map(|i| data[i])
+            .collect();
+
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let NdOperation::Transpose(a, b) = out.operation() else {
+                unreachable!()
+            };
+            let x = &out.prev()[0];
+            let mut view_strides = x.strides();
+            view_strides.swap(a, b);
+            let index = offsets(&out.shape(), &view_strides);
+            let grad = out.grad();
+            let mut input = x.0.borrow_mut();
+            for (k, i) in index.into_iter().enumerate() {
+                input.grad[i] += grad[k];
+            }
+        }
+
+        NdTensor::new_with_operation(
+            out,
+            &out_shape,
+            NdOperation::Transpose(a, b),
+            vec![self.clone()],
+            _backward,
+        )
+    }
+
+    // 2차원 행렬의 전치
+    pub fn t(&self) -> Self {
+        assert_eq!(self.ndim(), 2, "t() requires a 2-d tensor");
+        self.transpose(0, 1)
+    }
+
+    // ---- 행렬 곱
+
+    // (m, k) x (k, n) -> (m, n) 을 노드 하나로 기록
+    // backward: dA = dC * B^T, dB = A^T * dC
+    pub fn matmul(&self, rhs: &NdTensor<T>) -> Self {
+        let (a, b) = (self.0.borrow(), rhs.0.borrow());
+        assert!(
+            a.shape.len() == 2 && b.shape.len() == 2 && a.shape[1] == b.shape[0],
+            "cannot matmul {:?} and {:?}",
+            a.shape,
+            b.shape
+        );
+        let (m, k, n) = (a.shape[0], a.shape[1], b.shape[1]);
+        let mut data = vec![T::zero(); m * n];
+        gemm(&a.data, &b.data, &mut data, (m, k, n));
+        drop((a, b));
+
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let prev = out.prev();
+            let (l, r) = (&prev[0], &prev[1]);
+            let (l_shape, l_data) = (l.shape(), l.data());
+            let r_data = r.data();
+            let (m, k, n) = (l_shape[0], l_shape[1], out.shape()[1]);
+            let grad = out.grad();
+
+            let mut dl = vec![T::zero(); m * k];
+            gemm(&grad, &transposed(&r_data, k, n), &mut dl, (m, n, k));
+            let mut dr = vec![T::zero(); k * n];
+            gemm(&transposed(&l_data, m, k), &grad, &mut dr, (k, m, n));
+
+            for (g, d) in l.0.borrow_mut().grad.iter_mut().zip(dl) {
+                *g += d;
+            }
+            for (g, d) in r.0.borrow_mut().grad.iter_mut().zip(dr) {
+                *g += d;
+            }
+        }
+
+        NdTensor::new_with_operation(
+            data,
+            &[m, n],
+            NdOperation::MatMul,
+            vec![self.clone(), rhs.clone()],
+            _backward,
+        )
+    }
+
+    // 1차원 벡터의 내적, 결과는 0차원
+    pub fn dot(&self, rhs: &NdTensor<T>) -> Self {
+        let (a, b) = (self.0.borrow(), rhs.0.borrow());
+        assert!(
+            a.shape.len() == 1 && a.shape == b.shape,
+            "cannot dot {:?} and {:?}",
+            a.shape,
+            b.shape
+        );
+        let data = a.data.iter().zip(&b.data).map(|(x, y)| *x * *y).sum();
+        drop((a, b));
+
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            let prev = out.prev();
+            let (l, r) = (&prev[0], &prev[1]);
+            let (l_data, r_data) = (l.data(), r.data());
+            let grad = out.item_grad();
+
+            for (g, y) in l.0.borrow_mut().grad.iter_mut().zip(&r_data) {
+                *g += *y * grad;
+            }
+            for (g, x) in r.0.borrow_mut().grad.iter_mut().zip(&l_data) {
+                *g += *x * grad;
+            }
+        }
+
+        NdTensor::new_with_operation(
+            vec![data],
+            &[],
+            NdOperation::Dot,
+            vec![self.clone(), rhs.clone()],
+            _backward,
+        )
+    }
+
+    fn item_grad(&self) -> T {
+        self.0.borrow().grad[0]
+    }
+}
+
+// cache blocking 크기 (f64 기준 블록 하나가 32KB)
+const BLOCK: usize = 64;
+
+// 연속된 (m, k), (k, n) 행렬에 대해 out += a * b
+// 블록 단위로 돌아서 캐시에 올라온 a, b 조각을 재사용하고,
+// 가장 안쪽 루프는 b와 out의 한 행을 연속으로 읽음
+pub(super) fn gemm<T: Float>(a: &[T], b: &[T], out: &mut [T], (m, k, n): (usize, usize, usize)) {
+    for i0 in (0..m).step_by(BLOCK) {
+        for p0 in (0..k).step_by(BLOCK) {
+            for j0 in (0..n).step_by(BLOCK) {
+                let j1 = (j0 + BLOCK).min(n);
+                for i in i0..(i0 + BLOCK).min(m) {
+                    let row = &mut out[i * n + j0..i * n + j1];
+                    for p in p0..(p0 + BLOCK).min(k) {
+                        let x = a[i * k + p];
+                        for (o, y) in row.iter_mut().zip(&b[p * n + j0..p * n + j1]) {
+                            *o += x * *y;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// (rows, cols) 행렬의 전치를 연속된 배열로
+fn transposed<T: Float>(data: &[T], rows: usize, cols: usize) -> Vec<T> {
+    let mut out = Vec::with_capacity(data.len());
+    for j in 0..cols {
+        out.extend((0..rows).map(|i| data[i * cols + j]));
+    }
+    out
+}
+
+// mean 내부에서만 쓰는 임시 계산은 그래프에 남기지 않음
+fn no_record<R>(f: impl FnOnce() -> R) -> R {
+    super::no_grad(f)
+}
+
+// row-major 연속 배열의 stride
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+fn flat_index(index: &[usize], shape: &[usize], strides: &[usize]) -> usize {
+    assert_eq!(index.len(), shape.len(), "index has wrong number of axes");
+    index
+        .iter()
+        .zip(shape)
+        .zip(strides)
+        .map(|((i, n), s)| {
+            assert!(
+                i < n,
+                "index {:?} out of bounds for shape {:?}",
+                index,
+                shape
+            );
+            i * s
+        })
+        .sum()
+}
+
+// NumPy 규칙: 뒤쪽 축부터 맞추고, 크기가 같거나 한쪽이 1이어야 함
+fn broadcast_shape(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let n = a.len().max(b.len());
+    (0..n)
+        .map(|i| {
+            let x = if i + a.len() >= n {
+                a[i + a.len() - n]
+            } else {
+                1
+            };
+            let y = if i + b.len() >= n {
+                b[i + b.len() - n]
+            } else {
+                1
+            };
+            match (x, y) {
+                _ if x == y => x,
+                (1, _) => y,
+                (_, 1) => x,
+                _ => panic!("shapes {:?} and {:?} cannot be broadcast", a, b),
+            }
+        })
+        .collect()
+}
+
+// 입력을 out_shape으로 broadcast 했을 때의 stride (늘어난 축은 0)
+fn broadcast_strides(shape: &[usize], strides: &[usize], out_shape: &[usize]) -> Vec<usize> {
+    let offset = out_shape.len() - shape.len();
+    (0..out_shape.len())
+        .map(|i| {
+            if i < offset || shape[i - offset] == 1 {
+                0
+            } else {
+                strides[i - offset]
+            }
+        })
+        .collect()
+}
+
+// shape을 row-major 순서로 돌 때 각 위치의 (strides 기준) 배열 인덱스
+fn offsets(shape: &[usize], strides: &[usize]) -> Vec<usize> {
+    let len = shape.iter().product();
+    let mut result = Vec::with_capacity(len);
+    let mut index = vec![0; shape.len()];
+    let mut offset = 0;
+    for _ in 0..len {
+        result.push(offset);
+        // 마지막 축부터 하나씩 올림
+        for d in (0..shape.len()).rev() {
+            index[d] += 1;
+            offset += strides[d];
+            if index[d] < shape[d] {
+                break;
+            }
+            offset -= strides[d] * index[d];
+            index[d] = 0;
+        }
+    }
+    result
+}
+
+// axis를 줄인 shape과, 입력 원소마다 대응하는 출력 인덱스
+fn reduce_index(shape: &[usize], axis: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut keep = shape.to_vec();
+    keep[axis] = 1;
+    let index = offsets(
+        shape,
+        &broadcast_strides(&keep, &contiguous_strides(&keep), shape),
+    );
+    let mut out_shape = shape.to_vec();
+    out_shape.remove(axis);
+    (out_shape, index)
+}
+
+impl<T: Float> Debug for NdTensor<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let t = self.0.borrow();
+        write!(
+            f,
+            "NdTensor {{ {} | shape: {:?} | data: {:?} | grad: {:?} }}",
+            t.label, t.shape, t.data, t.grad
+        )
+    }
+}
+
+impl<T: Float> Add for &NdTensor<T> {
+    type Output = NdTensor<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::binary_backward(out, |_, _| (T::one(), T::one()));
+        }
+
+        self.binary(rhs, NdOperation::Add, |a, b| a + b, _backward)
+    }
+}
+
+impl<T: Float> Sub for &NdTensor<T> {
+    type Output = NdTensor<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::binary_backward(out, |_, _| (T::one(), -T::one()));
+        }
+
+        self.binary(rhs, NdOperation::Sub, |a, b| a - b, _backward)
+    }
+}
+
+impl<T: Float> Mul for &NdTensor<T> {
+    type Output = NdTensor<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::binary_backward(out, |a, b| (b, a));
+        }
+
+        self.binary(rhs, NdOperation::Mul, |a, b| a * b, _backward)
+    }
+}
+
+impl<T: Float> Div for &NdTensor<T> {
+    type Output = NdTensor<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::binary_backward(out, |a, b| (T::one() / b, -a / (b * b)));
+        }
+
+        self.binary(rhs, NdOperation::Div, |a, b| a / b, _backward)
+    }
+}
+
+impl<T: Float> Neg for &NdTensor<T> {
+    type Output = NdTensor<T>;
+
+    fn neg(self) -> Self::Output {
+        fn _backward<T: Float>(out: &NdTensor<T>) {
+            NdTensor::map_backward(out, |_, _| -T::one());
+        }
+
+        self.map(NdOperation::Neg, |x| -x, _backward)
+    }
+}
+
+// 값으로 받는 경우와 스칼라 연산은 참조 구현을 재사용 (스칼라는 0차원으로 broadcast)
+macro_rules! impl_nd_ops {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl<T: Float> $trait for NdTensor<T> {
+                type Output = NdTensor<T>;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<T: Float> $trait<T> for &NdTensor<T> {
+                type Output = NdTensor<T>;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.$method(&NdTensor::scalar(rhs))
+                }
+            }
+
+            impl<T: Float> $trait<T> for NdTensor<T> {
+                type Output = NdTensor<T>;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    (&self).$method(&NdTensor::scalar(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_nd_ops!(Add add, Sub sub, Mul mul, Div div);
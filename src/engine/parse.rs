@@ -0,0 +1,365 @@
+use std::fmt::Display;
+
+use super::{Float, Tensor, logsumexp};
+
+// 수식 문자열을 Tensor 그래프로 만듦
+// 변수는 bindings 중 label이 같은 Tensor를 그대로 사용하므로 backward 후 기울기가 그 leaf에 쌓임
+//
+// expr  := term (('+' | '-') term)*
+// term  := unary (('*' | '/') unary)*
+// unary := '-' unary | power
+// power := atom (('^' | '**') unary)?
+// atom  := number | name | name '(' expr (',' expr)* ')' | '(' expr ')'
+pub fn parse<T: Float>(src: &str, bindings: &[Tensor<T>]) -> Result<Tensor<T>, ParseError> {
+    let tokens = tokenize(src)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: src.len(),
+        bindings,
+    };
+    let value = parser.expr()?;
+    match parser.peek() {
+        None => Ok(value.into_tensor()),
+        Some((token, position)) => Err(ParseError::new(format!("unexpected {}", token), position)),
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParseError {
+    pub message: String,
+    // src 안의 byte 위치
+    pub position: usize,
+}
+
+impl ParseError {
+    pub(super) fn new(message: String, position: usize) -> Self {
+        Self { message, position }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Op(char),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "number {}", n),
+            Token::Name(name) => write!(f, "name {}", name),
+            Token::Op(op) => write!(f, "'{}'", op),
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+        }
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<(usize, char)> = src.char_indices().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let digit_at = |j: usize| chars.get(j).is_some_and(|(_, c)| c.is_ascii_digit());
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() || (c == '.' && digit_at(i + 1)) {
+            // 1, 1.5, .5, 1e-3
+            while digit_at(i) || chars.get(i).is_some_and(|(_, c)| *c == '.') {
+                i += 1;
+            }
+            if chars.get(i).is_some_and(|(_, c)| *c == 'e' || *c == 'E') {
+                let sign = chars
+                    .get(i + 1)
+                    .is_some_and(|(_, c)| *c == '+' || *c == '-');
+                let digits = if sign { i + 2 } else { i + 1 };
+                if digit_at(digits) {
+                    i = digits;
+                    while digit_at(i) {
+                        i += 1;
+                    }
+                }
+            }
+            let end = chars.get(i).map_or(src.len(), |(j, _)| *j);
+            let text = &src[start..end];
+            let n = text
+                .parse()
+                .map_err(|_| ParseError::new(format!("invalid number {}", text), start))?;
+            tokens.push((Token::Number(n), start));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            while chars
+                .get(i)
+                .is_some_and(|(_, c)| c.is_alphanumeric() || *c == '_')
+            {
+                i += 1;
+            }
+            let end = chars.get(i).map_or(src.len(), |(j, _)| *j);
+            tokens.push((Token::Name(src[start..end].into()), start));
+            continue;
+        }
+
+        let token = match c {
+            '*' if chars.get(i + 1).is_some_and(|(_, c)| *c == '*') => {
+                i += 1;
+                Token::Op('^')
+            }
+            '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            _ => return Err(ParseError::new(format!("unexpected '{}'", c), start)),
+        };
+        tokens.push((token, start));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+// 숫자끼리의 계산은 노드를 만들지 않고 바로 계산
+#[derive(Clone)]
+enum Value<T: Float> {
+    Number(T),
+    Node(Tensor<T>),
+}
+
+impl<T: Float> Value<T> {
+    fn into_tensor(self) -> Tensor<T> {
+        match self {
+            Value::Number(n) => Tensor::constant(n),
+            Value::Node(t) => t,
+        }
+    }
+}
+
+struct Parser<'a, T: Float> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    // 입력이 끝났을 때의 오류 위치
+    end: usize,
+    bindings: &'a [Tensor<T>],
+}
+
+impl<T: Float> Parser<'_, T> {
+    fn peek(&self) -> Option<(Token, usize)> {
+        self.tokens.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), ParseError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| ParseError::new("unexpected end of input".into(), self.end))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek().is_some_and(|(t, _)| t == *token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), ParseError> {
+        let (next, position) = self.next()?;
+        if next == token {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                format!("expected {}, found {}", token, next),
+                position,
+            ))
+        }
+    }
+
+    fn expr(&mut self) -> Result<Value<T>, ParseError> {
+        let mut lhs = self.term()?;
+        loop {
+            if self.eat(&Token::Op('+')) {
+                lhs = binary(lhs, self.term()?, |a, b| a + b, |a, b| a + b);
+            } else if self.eat(&Token::Op('-')) {
+                lhs = binary(lhs, self.term()?, |a, b| a - b, |a, b| a - b);
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Value<T>, ParseError> {
+        let mut lhs = self.unary()?;
+        loop {
+            if self.eat(&Token::Op('*')) {
+                lhs = binary(lhs, self.unary()?, |a, b| a * b, |a, b| a * b);
+            } else if self.eat(&Token::Op('/')) {
+                lhs = binary(lhs, self.unary()?, |a, b| a / b, |a, b| a / b);
+            } else {
+                return Ok(lhs);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Value<T>, ParseError> {
+        if self.eat(&Token::Op('-')) {
+            return Ok(match self.unary()? {
+                Value::Number(n) => Value::Number(-n),
+                Value::Node(t) => Value::Node(-&t),
+            });
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Value<T>, ParseError> {
+        let base = self.atom()?;
+        if !self.eat(&Token::Op('^')) {
+            return Ok(base);
+        }
+        // 오른쪽 결합: 2^3^2 = 2^(3^2)
+        let exponent = self.unary()?;
+        Ok(pow(base, exponent))
+    }
+
+    fn atom(&mut self) -> Result<Value<T>, ParseError> {
+        let (token, position) = self.next()?;
+        match token {
+            Token::Number(n) => Ok(Value::Number(T::from_f64(n))),
+            Token::LParen => {
+                let value = self.expr()?;
+                self.expect(Token::RParen)?;
+                Ok(value)
+            }
+            Token::Name(name) if self.eat(&Token::LParen) => {
+                let mut args = vec![self.expr()?];
+                while self.eat(&Token::Comma) {
+                    args.push(self.expr()?);
+                }
+                self.expect(Token::RParen)?;
+                call(&name, args, position)
+            }
+            Token::Name(name) => self
+                .bindings
+                .iter()
+                .find(|t| t.label() == name)
+                .map(|t| Value::Node(t.clone()))
+                .ok_or_else(|| ParseError::new(format!("unknown variable {}", name), position)),
+            token => Err(ParseError::new(format!("unexpected {}", token), position)),
+        }
+    }
+}
+
+fn binary<T: Float>(
+    lhs: Value<T>,
+    rhs: Value<T>,
+    number: fn(T, T) -> T,
+    node: fn(&Tensor<T>, &Tensor<T>) -> Tensor<T>,
+) -> Value<T> {
+    match (lhs, rhs) {
+        (Value::Number(a), Value::Number(b)) => Value::Number(number(a, b)),
+        (lhs, rhs) => Value::Node(node(&lhs.into_tensor(), &rhs.into_tensor())),
+    }
+}
+
+fn pow<T: Float>(base: Value<T>, exponent: Value<T>) -> Value<T> {
+    match (base, exponent) {
+        (Value::Number(a), Value::Number(b)) => Value::Number(a.powf(b)),
+        (Value::Node(t), Value::Number(n)) => Value::Node(t.pow(n)),
+        (base, Value::Node(e)) => Value::Node(base.into_tensor().pow(&e)),
+    }
+}
+
+// 내장 함수
+fn call<T: Float>(
+    name: &str,
+    args: Vec<Value<T>>,
+    position: usize,
+) -> Result<Value<T>, ParseError> {
+    let arity = match name {
+        // 인자 개수 제한 없음
+        "logsumexp" => args.len(),
+        "clamp" | "where" => 3,
+        "leaky_relu" | "elu" | "pow" | "max" | "min" => 2,
+        "tanh" | "exp" | "log" | "ln" | "sqrt" | "abs" | "sin" | "cos" | "sigmoid" | "relu"
+        | "softplus" | "gelu" | "silu" => 1,
+        _ => {
+            return Err(ParseError::new(
+                format!("unknown function {}", name),
+                position,
+            ));
+        }
+    };
+    if args.len() != arity {
+        return Err(ParseError::new(
+            format!("{} takes {} argument(s), got {}", name, arity, args.len()),
+            position,
+        ));
+    }
+
+    let number = |i: usize| match args[i] {
+        Value::Number(n) => Ok(n),
+        _ => Err(ParseError::new(
+            format!(
+                "{} argument of {} must be a number",
+                ["first", "second", "third"][i],
+                name
+            ),
+            position,
+        )),
+    };
+    let tensor = |i: usize| args[i].clone().into_tensor();
+    let t = tensor(0);
+    let result = match name {
+        "tanh" => t.tanh(),
+        "exp" => t.exp(),
+        "log" | "ln" => t.log(),
+        "sqrt" => t.sqrt(),
+        "abs" => t.abs(),
+        "sin" => t.sin(),
+        "cos" => t.cos(),
+        "sigmoid" => t.sigmoid(),
+        "relu" => t.relu(),
+        "softplus" => t.softplus(),
+        "gelu" => t.gelu(),
+        "silu" => t.silu(),
+        "leaky_relu" => t.leaky_relu(number(1)?),
+        "elu" => t.elu(number(1)?),
+        "pow" => return Ok(pow(Value::Node(t), args[1].clone())),
+        "max" => t.max(&tensor(1)),
+        "min" => t.min(&tensor(1)),
+        "clamp" => {
+            let (lo, hi) = (number(1)?, number(2)?);
+            if lo > hi {
+                return Err(ParseError::new("clamp requires lo <= hi".into(), position));
+            }
+            t.clamp(lo, hi)
+        }
+        "where" => Tensor::where_(&t, &tensor(1), &tensor(2)),
+        "logsumexp" => {
+            let xs: Vec<Tensor<T>> = (0..args.len()).map(tensor).collect();
+            logsumexp(&xs)
+        }
+        _ => unreachable!(),
+    };
+    Ok(Value::Node(result))
+}
@@ -0,0 +1,352 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Display,
+    rc::Rc,
+};
+
+use super::{CustomOp, Float, Operation, Tensor, is_grad_enabled, rules};
+
+// 명령 하나: values[out] = op(values[operands[start..start + len]])
+struct Instr<T: Float> {
+    op: Operation,
+    out: usize,
+    start: usize,
+    len: usize,
+    custom: Option<Rc<dyn CustomOp<T>>>,
+}
+
+// closure를 한 번 실행해서 만든 그래프를 평평한 명령 배열로 바꾼 것
+// 같은 구조의 그래프를 매번 새로 만들지 않고 값만 바꿔서 forward/backward를 반복
+//
+// slot 배치: [입력 | 캡처한 leaf | 중간 노드]
+// - 입력: forward에 넘기는 값, 기울기는 input_grads()
+// - 캡처한 leaf: closure 밖의 Tensor (파라미터, 상수), forward마다 현재 값을 읽고
+//   backward 결과는 원래 Tensor의 grad에 누적
+//
+// forward/backward는 미리 잡아둔 배열만 사용 (CustomOp의 backward 반환값은 제외)
+// hook은 호출되지 않음
+pub struct Program<T: Float = f64> {
+    values: Vec<T>,
+    grads: Vec<T>,
+    instrs: Vec<Instr<T>>,
+    operands: Vec<usize>,
+    n_inputs: usize,
+    captured: Vec<Tensor<T>>,
+    outputs: Vec<usize>,
+    // 명령 하나의 입력 값, 국소 기울기를 모으는 버퍼
+    scratch: Vec<T>,
+    locals: Vec<T>,
+}
+
+// Program::optimize 결과
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OptimizeReport {
+    pub nodes_before: usize,
+    pub nodes_after: usize,
+    // 상수로 접은 명령
+    pub folded: usize,
+    // 공통 부분식으로 합친 명령
+    pub merged: usize,
+    // x * y^-1 -> x / y
+    pub fused: usize,
+    // 쓰이지 않아 지운 명령
+    pub removed: usize,
+}
+
+impl Display for OptimizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "nodes {} -> {} (folded {}, merged {}, fused {}, removed {})",
+            self.nodes_before, self.nodes_after, self.folded, self.merged, self.fused, self.removed
+        )
+    }
+}
+
+impl<T: Float> Program<T> {
+    // n_inputs개의 입력 leaf로 f를 한 번 실행해서 기록
+    #[allow(clippy::mutable_key_type)]
+    pub fn trace<F>(n_inputs: usize, f: F) -> Self
+    where
+        F: FnOnce(&[Tensor<T>]) -> Vec<Tensor<T>>,
+    {
+        assert!(is_grad_enabled(), "cannot trace inside no_grad");
+
+        let inputs: Vec<Tensor<T>> = (0..n_inputs).map(|_| Tensor::leaf(T::zero())).collect();
+        let outputs = f(&inputs);
+        let nodes = Tensor::topological_sort_many(&outputs);
+
+        let mut slots: HashMap<Tensor<T>, usize> = HashMap::new();
+        for (i, x) in inputs.iter().enumerate() {
+            slots.insert(x.clone(), i);
+        }
+
+        let mut captured = Vec::new();
+        for node in &nodes {
+            if node.is_leaf() && !slots.contains_key(node) {
+                slots.insert(node.clone(), n_inputs + captured.len());
+                captured.push(node.clone());
+            }
+        }
+
+        let mut instrs = Vec::new();
+        let mut operands = Vec::new();
+        for node in &nodes {
+            // MatVec의 출력은 행마다 dot 명령 하나로 펼치므로 공유 노드는 명령이 없음
+            let (op, prev) = node.scalar_view();
+            if node.is_leaf() || matches!(op, Operation::MatVec(_)) {
+                continue;
+            }
+            let out = slots.len();
+            slots.insert(node.clone(), out);
+
+            instrs.push(Instr {
+                op,
+                out,
+                start: operands.len(),
+                len: prev.len(),
+                custom: node.custom_op(),
+            });
+            operands.extend(prev.iter().map(|p| slots[p]));
+        }
+
+        let outputs = outputs.iter().map(|o| slots[o]).collect();
+        let n = slots.len();
+        Program {
+            values: vec![T::zero(); n],
+            grads: vec![T::zero(); n],
+            instrs,
+            operands,
+            n_inputs,
+            captured,
+            outputs,
+            scratch: Vec::new(),
+            locals: Vec::new(),
+        }
+    }
+
+    pub fn num_inputs(&self) -> usize {
+        self.n_inputs
+    }
+
+    // 연산 노드 수
+    pub fn len(&self) -> usize {
+        self.instrs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instrs.is_empty()
+    }
+
+    pub fn num_slots(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn forward(&mut self, inputs: &[T]) {
+        assert_eq!(inputs.len(), self.n_inputs, "wrong number of inputs");
+        self.values[..self.n_inputs].copy_from_slice(inputs);
+        for (i, leaf) in self.captured.iter().enumerate() {
+            self.values[self.n_inputs + i] = leaf.data();
+        }
+
+        for instr in &self.instrs {
+            let args = &self.operands[instr.start..instr.start + instr.len];
+            let out = eval(instr, args, &self.values, &mut self.scratch);
+            self.values[instr.out] = out;
+        }
+    }
+
+    pub fn output(&self, i: usize) -> T {
+        self.values[self.outputs[i]]
+    }
+
+    pub fn outputs(&self) -> Vec<T> {
+        self.outputs.iter().map(|o| self.values[*o]).collect()
+    }
+
+    // 마지막 forward 기준으로 모든 출력의 기울기를 1로 시작 (출력의 합을 미분)
+    pub fn backward(&mut self) {
+        self.grads.fill(T::zero());
+        for o in &self.outputs {
+            self.grads[*o] += T::one();
+        }
+
+        for instr in self.instrs.iter().rev() {
+            let g = self.grads[instr.out];
+            let args = &self.operands[instr.start..instr.start + instr.len];
+            let out = self.values[instr.out];
+            self.scratch.clear();
+            self.scratch.extend(args.iter().map(|i| self.values[*i]));
+
+            if instr.op == Operation::Custom {
+                let op = instr.custom.as_ref().unwrap();
+                let grads = op.backward(&self.scratch, out, g);
+                for (i, d) in args.iter().zip(grads) {
+                    self.grads[*i] += d;
+                }
+                continue;
+            }
+            rules::partials(instr.op, &self.scratch, &out, &mut self.locals);
+            for (i, local) in args.iter().zip(&self.locals) {
+                self.grads[*i] += *local * g;
+            }
+        }
+
+        for (i, leaf) in self.captured.iter().enumerate() {
+            if !leaf.is_constant() {
+                leaf.set_grad(leaf.grad() + self.grads[self.n_inputs + i]);
+            }
+        }
+    }
+
+    // 마지막 backward에서 구한 입력별 기울기
+    pub fn input_grads(&self) -> &[T] {
+        &self.grads[..self.n_inputs]
+    }
+
+    // 출력에서 도달할 수 있는 노드 수 (입력, 캡처한 leaf, 상수, 연산 결과)
+    pub fn node_count(&self) -> usize {
+        self.live_slots().iter().filter(|live| **live).count()
+    }
+
+    fn live_slots(&self) -> Vec<bool> {
+        let mut live = vec![false; self.values.len()];
+        for o in &self.outputs {
+            live[*o] = true;
+        }
+        for instr in self.instrs.iter().rev() {
+            if live[instr.out] {
+                for a in &self.operands[instr.start..instr.start + instr.len] {
+                    live[*a] = true;
+                }
+            }
+        }
+        live
+    }
+
+    // 명령 배열에 대한 최적화
+    // - 상수 접기: 입력이 모두 상수(Tensor::constant)인 연산은 미리 계산, x + 0, x * 1 은 x로
+    // - 공통 부분식 제거: 같은 연산, 같은 입력인 명령은 하나만 남김 (CustomOp 제외)
+    // - x * y^-1 을 나눗셈 명령 하나로 합침
+    // - 출력에 쓰이지 않는 명령 제거
+    pub fn optimize(&mut self) -> OptimizeReport {
+        let nodes_before = self.node_count();
+        let n = self.values.len();
+
+        let mut constant = vec![false; n];
+        for (i, leaf) in self.captured.iter().enumerate() {
+            self.values[self.n_inputs + i] = leaf.data();
+            constant[self.n_inputs + i] = leaf.is_constant();
+        }
+
+        let mut remap: Vec<usize> = (0..n).collect();
+        // (연산, 입력) -> 결과 slot
+        let mut seen: HashMap<(String, Vec<usize>), usize> = HashMap::new();
+        // y^-1 결과 slot -> y
+        let mut reciprocal: HashMap<usize, usize> = HashMap::new();
+        let mut report = OptimizeReport {
+            nodes_before,
+            ..Default::default()
+        };
+
+        let mut instrs = Vec::new();
+        let mut operands = Vec::new();
+        for mut instr in std::mem::take(&mut self.instrs) {
+            let mut args: Vec<usize> = self.operands[instr.start..instr.start + instr.len]
+                .iter()
+                .map(|a| remap[*a])
+                .collect();
+
+            if instr.op == Operation::Mul {
+                if let Some(y) = reciprocal.get(&args[1]) {
+                    args = vec![args[0], *y];
+                    instr.op = Operation::Div;
+                    report.fused += 1;
+                } else if let Some(y) = reciprocal.get(&args[0]) {
+                    args = vec![args[1], *y];
+                    instr.op = Operation::Div;
+                    report.fused += 1;
+                }
+            }
+
+            if !args.is_empty() && args.iter().all(|a| constant[*a]) {
+                self.values[instr.out] = eval(&instr, &args, &self.values, &mut self.scratch);
+                constant[instr.out] = true;
+                report.folded += 1;
+                continue;
+            }
+            if let Some(x) = self.identity(&instr, &args, &constant) {
+                remap[instr.out] = x;
+                report.folded += 1;
+                continue;
+            }
+
+            if instr.op != Operation::Custom {
+                let mut key = args.clone();
+                if matches!(instr.op, Operation::Add | Operation::Mul) {
+                    key.sort();
+                }
+                match seen.entry((format!("{:?}", instr.op), key)) {
+                    Entry::Occupied(slot) => {
+                        remap[instr.out] = *slot.get();
+                        report.merged += 1;
+                        continue;
+                    }
+                    Entry::Vacant(slot) => {
+                        slot.insert(instr.out);
+                    }
+                }
+            }
+
+            if instr.op == Operation::Powf(-1.0) {
+                reciprocal.insert(instr.out, args[0]);
+            }
+            instr.start = operands.len();
+            instr.len = args.len();
+            operands.extend(args);
+            instrs.push(instr);
+        }
+
+        for o in &mut self.outputs {
+            *o = remap[*o];
+        }
+        self.instrs = instrs;
+        self.operands = operands;
+
+        // 쓰이지 않게 된 명령 제거 (합쳐진 y^-1 등)
+        let live = self.live_slots();
+        let before = self.instrs.len();
+        self.instrs.retain(|instr| live[instr.out]);
+        report.removed = before - self.instrs.len();
+
+        report.nodes_after = self.node_count();
+        report
+    }
+
+    // x + 0, 0 + x, x * 1, 1 * x 이면 x의 slot
+    fn identity(&self, instr: &Instr<T>, args: &[usize], constant: &[bool]) -> Option<usize> {
+        let unit = match instr.op {
+            Operation::Add => T::zero(),
+            Operation::Mul => T::one(),
+            _ => return None,
+        };
+        let is_unit = |a: usize| constant[a] && self.values[a] == unit;
+        if is_unit(args[1]) {
+            Some(args[0])
+        } else if is_unit(args[0]) {
+            Some(args[1])
+        } else {
+            None
+        }
+    }
+}
+
+// 명령 하나의 결과 값
+fn eval<T: Float>(instr: &Instr<T>, args: &[usize], v: &[T], scratch: &mut Vec<T>) -> T {
+    scratch.clear();
+    scratch.extend(args.iter().map(|i| v[*i]));
+    match instr.op {
+        Operation::Custom => instr.custom.as_ref().unwrap().forward(scratch),
+        op => rules::value(op, scratch),
+    }
+}
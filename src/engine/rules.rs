@@ -0,0 +1,417 @@
+use super::{
+    Float, Operation, Tensor,
+    expr::{Expr, Func},
+    softmax::logsumexp_value,
+};
+
+// 연산별 값과 미분 규칙을 한 곳에 모아 둠
+// - value: 연산 결과 값 (Tensor 연산, Program::forward, Func::apply)
+// - partials: 입력별 국소 기울기 d out / d x_i
+//   값(T)으로 계산하면 backward와 Program::backward,
+//   Tensor로 계산하면 backward_create_graph, Expr로 계산하면 symbolic_grad 에서 사용
+
+// sqrt(2 / pi)
+pub(super) const GELU_C: f64 = 0.797_884_560_802_865_4;
+pub(super) const GELU_K: f64 = 0.044715;
+
+pub(super) fn value<T: Float>(op: Operation, x: &[T]) -> T {
+    let c = T::from_f64;
+    let positive = x.first().is_some_and(|x| *x > T::zero());
+
+    match op {
+        // MatVec의 출력은 Tensor::scalar_view 로 Dot이 되어서 들어옴
+        Operation::None | Operation::Custom | Operation::MatVec(_) | Operation::Row(_) => {
+            unreachable!()
+        }
+        Operation::Neg => -x[0],
+        Operation::Add => x[0] + x[1],
+        Operation::Mul => x[0] * x[1],
+        Operation::Div => x[0] / x[1],
+        Operation::Pow => x[0].powf(x[1]),
+        Operation::Powf(n) => x[0].powf(c(n)),
+        Operation::Tanh => x[0].tanh(),
+        Operation::Exp => x[0].exp(),
+        Operation::Relu => x[0].max(T::zero()),
+        Operation::LeakyRelu(alpha) => {
+            if positive {
+                x[0]
+            } else {
+                c(alpha) * x[0]
+            }
+        }
+        Operation::Sigmoid => sigmoid(x[0]),
+        Operation::Log => x[0].ln(),
+        Operation::Sqrt => x[0].sqrt(),
+        Operation::Abs => x[0].abs(),
+        Operation::Sin => x[0].sin(),
+        Operation::Cos => x[0].cos(),
+        // ln(1 + e^x), 큰 x에서 overflow 되지 않도록 max(x, 0) + ln(1 + e^-|x|) 로 계산
+        Operation::Softplus => x[0].max(T::zero()) + (-x[0].abs()).exp().ln_1p(),
+        // tanh 근사식: 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))
+        Operation::Gelu => {
+            let t = (c(GELU_C) * (x[0] + c(GELU_K) * x[0].powi(3))).tanh();
+            c(0.5) * x[0] * (T::one() + t)
+        }
+        Operation::Silu => x[0] * sigmoid(x[0]),
+        Operation::Elu(alpha) => {
+            if positive {
+                x[0]
+            } else {
+                c(alpha) * x[0].exp_m1()
+            }
+        }
+        Operation::Dot => {
+            let (a, b) = x.split_at(x.len() / 2);
+            a.iter().zip(b).map(|(a, b)| *a * *b).sum()
+        }
+        Operation::Max => x[0].max(x[1]),
+        Operation::Min => x[0].min(x[1]),
+        Operation::Clamp(lo, hi) => x[0].max(c(lo)).min(c(hi)),
+        Operation::Where => {
+            if x[0] != T::zero() {
+                x[1]
+            } else {
+                x[2]
+            }
+        }
+        Operation::Softmax => (x[0] - x[1]).exp(),
+        Operation::LogSoftmax => x[0] - x[1],
+        Operation::LogSumExp => logsumexp_value(x),
+    }
+}
+
+// 입력 순서대로 d out / d x_i 를 locals에 채움 (CustomOp, MatVec 제외)
+// out은 이 연산의 결과, 미분할 수 없는 점에서는 아래 subgradient를 사용
+pub(super) fn partials<S: Scalar>(op: Operation, x: &[S], out: &S, locals: &mut Vec<S>) {
+    let c = S::lit;
+    let one = || c(1.0);
+    locals.clear();
+
+    match op {
+        Operation::None => {}
+        Operation::Custom | Operation::MatVec(_) | Operation::Row(_) => unreachable!(),
+        Operation::Neg => locals.push(c(-1.0)),
+        Operation::Add => locals.extend([one(), one()]),
+        Operation::Mul => locals.extend([x[1].clone(), x[0].clone()]),
+        // d/dy (x / y) = -x / y^2 = -out / y
+        Operation::Div => locals.extend([one().div(&x[1]), out.div(&x[1]).neg()]),
+        // d/dx = y * x^(y-1), d/dy = x^y * ln(x)
+        // x <= 0 에서는 ln(x)가 정의되지 않으므로 지수 쪽 기울기는 0
+        Operation::Pow => locals.extend([
+            x[1].mul(&x[0].pow(&x[1].sub(&one()))),
+            S::select(&x[0].step(), &out.mul(&x[0].ln()), &c(0.0)),
+        ]),
+        Operation::Powf(n) => locals.push(c(n).mul(&x[0].powf(n - 1.0))),
+        Operation::Tanh => locals.push(one().sub(&out.powf(2.0))),
+        Operation::Exp => locals.push(out.clone()),
+        Operation::Relu => locals.push(x[0].step()),
+        Operation::LeakyRelu(alpha) => {
+            let step = x[0].step();
+            locals.push(step.add(&c(alpha).mul(&one().sub(&step))));
+        }
+        Operation::Sigmoid => locals.push(out.mul(&one().sub(out))),
+        Operation::Log => locals.push(one().div(&x[0])),
+        Operation::Sqrt => locals.push(c(0.5).div(out)),
+        Operation::Abs => locals.push(x[0].sign()),
+        Operation::Sin => locals.push(x[0].cos()),
+        Operation::Cos => locals.push(x[0].sin().neg()),
+        Operation::Softplus => locals.push(x[0].sigmoid()),
+        Operation::Gelu => {
+            let x = &x[0];
+            let inner = c(GELU_C).mul(&x.add(&c(GELU_K).mul(&x.powf(3.0))));
+            let t = inner.tanh();
+            let left = c(0.5).mul(&one().add(&t));
+            let right = c(0.5 * GELU_C)
+                .mul(x)
+                .mul(&one().sub(&t.powf(2.0)))
+                .mul(&one().add(&c(3.0 * GELU_K).mul(&x.powf(2.0))));
+            locals.push(left.add(&right));
+        }
+        Operation::Silu => {
+            let s = x[0].sigmoid();
+            locals.push(s.add(&x[0].mul(&s).mul(&one().sub(&s))));
+        }
+        // x <= 0 에서 d/dx = alpha * e^x = out + alpha
+        Operation::Elu(alpha) => {
+            let step = x[0].step();
+            locals.push(step.add(&one().sub(&step).mul(&out.add(&c(alpha)))));
+        }
+        Operation::Dot => {
+            let (a, b) = x.split_at(x.len() / 2);
+            locals.extend(b.iter().cloned());
+            locals.extend(a.iter().cloned());
+        }
+        Operation::Max => locals.extend([S::max_share(&x[0], &x[1]), S::max_share(&x[1], &x[0])]),
+        Operation::Min => locals.extend([S::max_share(&x[1], &x[0]), S::max_share(&x[0], &x[1])]),
+        Operation::Clamp(lo, hi) => locals.push(x[0].indicator(lo, hi)),
+        // 기울기는 선택된 쪽으로만 가고 cond로는 가지 않음
+        Operation::Where => locals.extend([
+            c(0.0),
+            S::select(&x[0], &one(), &c(0.0)),
+            S::select(&x[0], &c(0.0), &one()),
+        ]),
+        // x = [x_i, lse], 입력 쪽 미분은 공유하는 logsumexp 노드가 모아서 한 번에 전달
+        Operation::Softmax => locals.extend([out.clone(), out.neg()]),
+        Operation::LogSoftmax => locals.extend([one(), one().neg()]),
+        // d lse / d x_j = s_j = exp(x_j - lse), 노드에 저장된 lse 값으로 다시 계산하지 않음
+        Operation::LogSumExp => locals.extend(x.iter().map(|x_j| x_j.sub(out).exp())),
+    }
+}
+
+// partials 에서 쓰는 연산
+// 구간별 상수인 값(step, sign 등)은 미분하면 0인 상수로 취급
+pub(super) trait Scalar: Clone {
+    fn lit(c: f64) -> Self;
+    fn add(&self, rhs: &Self) -> Self;
+    fn sub(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+    fn div(&self, rhs: &Self) -> Self;
+    fn neg(&self) -> Self;
+    fn pow(&self, rhs: &Self) -> Self;
+    fn powf(&self, n: f64) -> Self;
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn tanh(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn sigmoid(&self) -> Self;
+    // x > 0 이면 1, 아니면 0
+    fn step(&self) -> Self;
+    // x = 0 에서는 0
+    fn sign(&self) -> Self;
+    // max(a, b)의 기울기 중 a 쪽 비율 (max_share)
+    fn max_share(a: &Self, b: &Self) -> Self;
+    // clamp(x, lo, hi)의 기울기 (indicator)
+    fn indicator(&self, lo: f64, hi: f64) -> Self;
+    // cond가 0이 아니면 a, 아니면 b
+    fn select(cond: &Self, a: &Self, b: &Self) -> Self;
+}
+
+impl<T: Float> Scalar for T {
+    fn lit(c: f64) -> Self {
+        T::from_f64(c)
+    }
+    fn add(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+    fn sub(&self, rhs: &Self) -> Self {
+        *self - *rhs
+    }
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+    fn div(&self, rhs: &Self) -> Self {
+        *self / *rhs
+    }
+    fn neg(&self) -> Self {
+        -*self
+    }
+    fn pow(&self, rhs: &Self) -> Self {
+        Float::powf(*self, *rhs)
+    }
+    fn powf(&self, n: f64) -> Self {
+        Float::powf(*self, T::from_f64(n))
+    }
+    fn exp(&self) -> Self {
+        Float::exp(*self)
+    }
+    fn ln(&self) -> Self {
+        Float::ln(*self)
+    }
+    fn tanh(&self) -> Self {
+        Float::tanh(*self)
+    }
+    fn sin(&self) -> Self {
+        Float::sin(*self)
+    }
+    fn cos(&self) -> Self {
+        Float::cos(*self)
+    }
+    fn sigmoid(&self) -> Self {
+        sigmoid(*self)
+    }
+    fn step(&self) -> Self {
+        step(*self)
+    }
+    fn sign(&self) -> Self {
+        sign(*self)
+    }
+    fn max_share(a: &Self, b: &Self) -> Self {
+        max_share(*a, *b)
+    }
+    fn indicator(&self, lo: f64, hi: f64) -> Self {
+        indicator(*self, T::from_f64(lo), T::from_f64(hi))
+    }
+    fn select(cond: &Self, a: &Self, b: &Self) -> Self {
+        if *cond != T::zero() { *a } else { *b }
+    }
+}
+
+// 기울기 그래프: 구간별 상수는 현재 값으로 계산한 상수 노드
+impl<T: Float> Scalar for Tensor<T> {
+    fn lit(c: f64) -> Self {
+        Tensor::constant(T::from_f64(c))
+    }
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+    fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+    fn div(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+    fn neg(&self) -> Self {
+        -self
+    }
+    fn pow(&self, rhs: &Self) -> Self {
+        Tensor::pow(self, rhs)
+    }
+    fn powf(&self, n: f64) -> Self {
+        Tensor::pow(self, T::from_f64(n))
+    }
+    fn exp(&self) -> Self {
+        Tensor::exp(self)
+    }
+    fn ln(&self) -> Self {
+        self.log()
+    }
+    fn tanh(&self) -> Self {
+        Tensor::tanh(self)
+    }
+    fn sin(&self) -> Self {
+        Tensor::sin(self)
+    }
+    fn cos(&self) -> Self {
+        Tensor::cos(self)
+    }
+    fn sigmoid(&self) -> Self {
+        Tensor::sigmoid(self)
+    }
+    fn step(&self) -> Self {
+        Tensor::constant(step(self.data()))
+    }
+    fn sign(&self) -> Self {
+        Tensor::constant(sign(self.data()))
+    }
+    fn max_share(a: &Self, b: &Self) -> Self {
+        Tensor::constant(max_share(a.data(), b.data()))
+    }
+    fn indicator(&self, lo: f64, hi: f64) -> Self {
+        Tensor::constant(indicator(self.data(), T::from_f64(lo), T::from_f64(hi)))
+    }
+    fn select(cond: &Self, a: &Self, b: &Self) -> Self {
+        if cond.data() != T::zero() {
+            a.clone()
+        } else {
+            b.clone()
+        }
+    }
+}
+
+impl Scalar for Expr {
+    fn lit(c: f64) -> Self {
+        Expr::Const(c)
+    }
+    fn add(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+    fn sub(&self, rhs: &Self) -> Self {
+        self.clone() - rhs.clone()
+    }
+    fn mul(&self, rhs: &Self) -> Self {
+        self.clone() * rhs.clone()
+    }
+    fn div(&self, rhs: &Self) -> Self {
+        self.clone() / rhs.clone()
+    }
+    fn neg(&self) -> Self {
+        -self.clone()
+    }
+    fn pow(&self, rhs: &Self) -> Self {
+        Expr::pow(self.clone(), rhs.clone())
+    }
+    fn powf(&self, n: f64) -> Self {
+        Expr::pow(self.clone(), Expr::Const(n))
+    }
+    fn exp(&self) -> Self {
+        Expr::func(Func::Exp, self.clone())
+    }
+    fn ln(&self) -> Self {
+        Expr::func(Func::Log, self.clone())
+    }
+    fn tanh(&self) -> Self {
+        Expr::func(Func::Tanh, self.clone())
+    }
+    fn sin(&self) -> Self {
+        Expr::func(Func::Sin, self.clone())
+    }
+    fn cos(&self) -> Self {
+        Expr::func(Func::Cos, self.clone())
+    }
+    fn sigmoid(&self) -> Self {
+        Expr::func(Func::Sigmoid, self.clone())
+    }
+    fn step(&self) -> Self {
+        Expr::func(Func::Step, self.clone())
+    }
+    fn sign(&self) -> Self {
+        Expr::func(Func::Sign, self.clone())
+    }
+    fn max_share(a: &Self, b: &Self) -> Self {
+        Expr::func(Func::Heaviside, a.clone() - b.clone())
+    }
+    fn indicator(&self, lo: f64, hi: f64) -> Self {
+        Expr::func(Func::Indicator(lo, hi), self.clone())
+    }
+    fn select(cond: &Self, a: &Self, b: &Self) -> Self {
+        Expr::select(cond.clone(), a.clone(), b.clone())
+    }
+}
+
+// 큰 |x|에서도 overflow 되지 않는 sigmoid
+pub(super) fn sigmoid<T: Float>(x: T) -> T {
+    if x >= T::zero() {
+        T::one() / (T::one() + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (T::one() + e)
+    }
+}
+
+pub(super) fn step<T: Float>(x: T) -> T {
+    if x > T::zero() { T::one() } else { T::zero() }
+}
+
+// x = 0 에서는 0
+pub(super) fn sign<T: Float>(x: T) -> T {
+    if x > T::zero() {
+        T::one()
+    } else if x < T::zero() {
+        -T::one()
+    } else {
+        T::zero()
+    }
+}
+
+// max(a, b)의 기울기 중 a 쪽 비율
+// 미분할 수 없는 꺾인 점(두 값이 같을 때)에서는 subgradient 구간의 가운데인 0.5를 사용
+// max, min, clamp 모두 이 규칙을 따르므로 clamp(x, lo, hi)와 min(max(x, lo), hi)의 기울기가 같음
+pub(super) fn max_share<T: Float>(a: T, b: T) -> T {
+    if a > b {
+        T::one()
+    } else if a < b {
+        T::zero()
+    } else {
+        T::from_f64(0.5)
+    }
+}
+
+// clamp(x, lo, hi)의 기울기, min(max(x, lo), hi)를 max_share로 미분한 것과 같음
+// lo < x < hi 이면 1, 경계에서는 0.5, 범위 밖에서는 0
+pub(super) fn indicator<T: Float>(x: T, lo: T, hi: T) -> T {
+    max_share(x, lo) * max_share(hi, x)
+}
@@ -0,0 +1,505 @@
+use std::{collections::HashMap, fmt::Write};
+
+use super::{Float, Operation, ParseError, Tensor, logsumexp};
+
+// 저장 형식이 바뀌면 올림
+const VERSION: f64 = 1.0;
+
+// 그래프 전체를 JSON으로 저장
+// 노드는 위상 정렬 순서로 한 줄에 하나씩 쓰므로 회귀 테스트의 snapshot diff를 읽기 쉬움
+//
+// {
+//   "version": 1,
+//   "root": 2,
+//   "nodes": [
+//     {"id": 0, "op": "leaf", "label": "x", "data": 2, "grad": 0, "prev": []},
+//     {"id": 1, "op": "powf", "arg": 2, "label": "", "data": 4, "grad": 0, "prev": [0]},
+//     ...
+//   ]
+// }
+impl<T: Float> Tensor<T> {
+    #[allow(clippy::mutable_key_type)]
+    pub fn to_json(&self) -> String {
+        let nodes = self.topological_sort();
+        let ids: HashMap<Tensor<T>, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.clone(), i))
+            .collect();
+
+        let mut json = String::new();
+        writeln!(json, "{{").unwrap();
+        writeln!(json, "  \"version\": {},", VERSION).unwrap();
+        writeln!(json, "  \"root\": {},", ids[self]).unwrap();
+        writeln!(json, "  \"nodes\": [").unwrap();
+        for (i, node) in nodes.iter().enumerate() {
+            let (op, args) = op_name(node);
+            write!(json, "    {{\"id\": {}, \"op\": {}", i, quote(&op)).unwrap();
+            match args.as_slice() {
+                [] => {}
+                [arg] => write!(json, ", \"arg\": {}", number(*arg)).unwrap(),
+                args => {
+                    let args: Vec<String> = args.iter().map(|a| number(*a)).collect();
+                    write!(json, ", \"arg\": [{}]", args.join(", ")).unwrap();
+                }
+            }
+            let prev: Vec<String> = node.prev().iter().map(|p| ids[p].to_string()).collect();
+            write!(
+                json,
+                ", \"label\": {}, \"data\": {}, \"grad\": {}, \"prev\": [{}]}}",
+                quote(&node.label()),
+                number(node.data().to_f64()),
+                number(node.grad().to_f64()),
+                prev.join(", ")
+            )
+            .unwrap();
+            writeln!(json, "{}", if i + 1 < nodes.len() { "," } else { "" }).unwrap();
+        }
+        writeln!(json, "  ]").unwrap();
+        writeln!(json, "}}").unwrap();
+
+        json
+    }
+
+    // to_json의 결과를 연산을 다시 실행해서 살아 있는 그래프로 만듦 (root를 반환)
+    // leaf의 값과 모든 노드의 label, grad는 저장된 값으로 복원
+    // CustomOp은 구현을 저장할 수 없으므로 불러올 수 없음
+    pub fn from_json(src: &str) -> Result<Tensor<T>, ParseError> {
+        let json = JsonParser::new(src).parse()?;
+        let version = json.field("version")?.as_f64()?;
+        if version != VERSION {
+            return Err(json.error(format!("unsupported version {}", version)));
+        }
+
+        let nodes = json.field("nodes")?.as_array()?;
+        let mut built: Vec<Tensor<T>> = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            let id = node.field("id")?.as_index()?;
+            if id != built.len() {
+                return Err(node.error(format!("expected id {}, found {}", built.len(), id)));
+            }
+            let prev = node
+                .field("prev")?
+                .as_array()?
+                .iter()
+                .map(|p| {
+                    let i = p.as_index()?;
+                    // 위상 정렬 순서이므로 부모는 항상 먼저 나옴
+                    built
+                        .get(i)
+                        .cloned()
+                        .ok_or_else(|| p.error(format!("unknown node {}", i)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let op = node.field("op")?.as_str()?;
+            // 숫자 하나 또는 (clamp처럼 여러 개면) 배열
+            let arg = match node.get("arg") {
+                Some(arg) => match arg.as_array() {
+                    Ok(items) => items.iter().map(|a| a.as_f64()).collect::<Result<_, _>>()?,
+                    Err(_) => vec![arg.as_f64()?],
+                },
+                None => vec![],
+            };
+            let data = T::from_f64(node.field("data")?.as_f64()?);
+            let tensor = replay(op, arg, data, &prev).map_err(|message| node.error(message))?;
+
+            tensor.set_label(node.field("label")?.as_str()?);
+            tensor.set_grad(T::from_f64(node.field("grad")?.as_f64()?));
+            built.push(tensor);
+        }
+
+        let root = json.field("root")?;
+        let index = root.as_index()?;
+        built
+            .get(index)
+            .cloned()
+            .ok_or_else(|| root.error(format!("unknown node {}", index)))
+    }
+}
+
+fn op_name<T: Float>(node: &Tensor<T>) -> (String, Vec<f64>) {
+    let name = match node.operation() {
+        Operation::None if node.is_constant() => "constant",
+        Operation::None => "leaf",
+        Operation::Powf(n) => return ("powf".into(), vec![n]),
+        Operation::LeakyRelu(alpha) => return ("leaky_relu".into(), vec![alpha]),
+        Operation::Elu(alpha) => return ("elu".into(), vec![alpha]),
+        Operation::Clamp(lo, hi) => return ("clamp".into(), vec![lo, hi]),
+        Operation::MatVec(rows) => return ("matvec".into(), vec![rows as f64]),
+        Operation::Row(i) => return ("row".into(), vec![i as f64]),
+        // Program 안에서만 쓰이는 연산
+        Operation::Div => unreachable!(),
+        Operation::Custom => return (format!("custom:{}", node.op_label()), vec![]),
+        op => return (op.to_string(), vec![]),
+    };
+    (name.into(), vec![])
+}
+
+// 저장된 연산을 같은 Tensor 메서드로 다시 실행
+fn replay<T: Float>(
+    op: &str,
+    arg: Vec<f64>,
+    data: T,
+    prev: &[Tensor<T>],
+) -> Result<Tensor<T>, String> {
+    if let Some(name) = op.strip_prefix("custom:") {
+        return Err(format!("custom op {} cannot be loaded", name));
+    }
+    let arity = match op {
+        "leaf" | "constant" => 0,
+        "+" | "*" | "pow" | "max" | "min" | "softmax" | "log_softmax" => 2,
+        "where" => 3,
+        "logsumexp" if !prev.is_empty() => prev.len(),
+        "matvec" => prev.len(),
+        "row" => 1,
+        "dot" if prev.len().is_multiple_of(2) => prev.len(),
+        "dot" => return Err("dot requires an even number of inputs".into()),
+        _ => 1,
+    };
+    if prev.len() != arity {
+        return Err(format!(
+            "{} takes {} input(s), found {}",
+            op,
+            arity,
+            prev.len()
+        ));
+    }
+    // 행 수, 출력 위치처럼 max 이하의 음이 아닌 정수여야 하는 arg (usize로 바꾸기 전에 확인)
+    let index = |i: usize, max: usize| match arg.get(i) {
+        Some(a) if *a >= 0.0 && a.fract() == 0.0 && *a <= max as f64 => Ok(*a as usize),
+        Some(a) => Err(format!(
+            "{} requires an integer arg in 0..={}, found {}",
+            op, max, a
+        )),
+        None => Err(format!("{} requires arg", op)),
+    };
+    let arg = |i: usize| {
+        arg.get(i)
+            .map(|a| T::from_f64(*a))
+            .ok_or_else(|| format!("{} requires arg", op))
+    };
+
+    let x = || &prev[0];
+    Ok(match op {
+        "leaf" => Tensor::leaf(data),
+        "constant" => Tensor::constant(data),
+        "neg" => -x(),
+        "+" => &prev[0] + &prev[1],
+        "*" => &prev[0] * &prev[1],
+        "pow" => prev[0].pow(&prev[1]),
+        "powf" => x().pow(arg(0)?),
+        "tanh" => x().tanh(),
+        "exp" => x().exp(),
+        "relu" => x().relu(),
+        "leaky_relu" => x().leaky_relu(arg(0)?),
+        "sigmoid" => x().sigmoid(),
+        "log" => x().log(),
+        "sqrt" => x().sqrt(),
+        "abs" => x().abs(),
+        "sin" => x().sin(),
+        "cos" => x().cos(),
+        "softplus" => x().softplus(),
+        "gelu" => x().gelu(),
+        "silu" => x().silu(),
+        "elu" => x().elu(arg(0)?),
+        "dot" => {
+            let (a, b) = prev.split_at(prev.len() / 2);
+            Tensor::dot(a, b)
+        }
+        "max" => prev[0].max(&prev[1]),
+        "min" => prev[0].min(&prev[1]),
+        "clamp" => {
+            let (lo, hi) = (arg(0)?, arg(1)?);
+            if lo > hi {
+                return Err("clamp requires lo <= hi".into());
+            }
+            x().clamp(lo, hi)
+        }
+        "where" => Tensor::where_(&prev[0], &prev[1], &prev[2]),
+        // 공유하는 logsumexp 노드는 앞에서 이미 만들어짐
+        "softmax" => Tensor::from_operation(Operation::Softmax, prev.to_vec()),
+        "log_softmax" => Tensor::from_operation(Operation::LogSoftmax, prev.to_vec()),
+        "logsumexp" => logsumexp(prev),
+        "matvec" => {
+            // _prev = [w (rows x n), x (n)] 이므로 rows * n + n 개
+            let rows = index(0, prev.len())?;
+            let n = rows.checked_add(1).map(|r| prev.len() / r);
+            if n.is_none_or(|n| rows * n + n != prev.len()) {
+                return Err(format!("matvec inputs do not match {} rows", rows));
+            }
+            Tensor::matvec_node(rows, prev.to_vec())
+        }
+        "row" => {
+            let i = index(0, usize::MAX)?;
+            match prev[0].operation() {
+                Operation::MatVec(rows) if i < rows => Tensor::matvec_row(&prev[0], i),
+                _ => return Err(format!("row {} is not an output of a matvec node", i)),
+            }
+        }
+        // Div는 Program 최적화에서만 생기므로 Tensor 그래프에는 나오지 않음
+        _ => return Err(format!("unknown op {}", op)),
+    })
+}
+
+// JSON 숫자는 NaN, inf를 표현할 수 없으므로 문자열로 저장
+fn number(x: f64) -> String {
+    if x.is_finite() {
+        x.to_string()
+    } else {
+        quote(&x.to_string())
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// 그래프 형식을 읽는 데 필요한 만큼만 구현한 JSON
+// 오류 위치를 알려주기 위해 값마다 시작 위치를 같이 저장
+struct Json {
+    value: JsonValue,
+    position: usize,
+}
+
+enum JsonValue {
+    Null,
+    Bool,
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn error(&self, message: String) -> ParseError {
+        ParseError::new(message, self.position)
+    }
+
+    fn get(&self, key: &str) -> Option<&Json> {
+        match &self.value {
+            JsonValue::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn field(&self, key: &str) -> Result<&Json, ParseError> {
+        self.get(key)
+            .ok_or_else(|| self.error(format!("missing field {}", key)))
+    }
+
+    fn as_f64(&self) -> Result<f64, ParseError> {
+        match &self.value {
+            JsonValue::Number(n) => Ok(*n),
+            JsonValue::String(s) => s
+                .parse()
+                .map_err(|_| self.error(format!("invalid number {}", s))),
+            _ => Err(self.error("expected number".into())),
+        }
+    }
+
+    fn as_index(&self) -> Result<usize, ParseError> {
+        let n = self.as_f64()?;
+        if n >= 0.0 && n.fract() == 0.0 {
+            Ok(n as usize)
+        } else {
+            Err(self.error(format!("invalid index {}", n)))
+        }
+    }
+
+    fn as_str(&self) -> Result<&str, ParseError> {
+        match &self.value {
+            JsonValue::String(s) => Ok(s),
+            _ => Err(self.error("expected string".into())),
+        }
+    }
+
+    fn as_array(&self) -> Result<&[Json], ParseError> {
+        match &self.value {
+            JsonValue::Array(items) => Ok(items),
+            _ => Err(self.error("expected array".into())),
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Json, ParseError> {
+        let value = self.value()?;
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(value),
+            Some(c) => Err(self.error(format!("unexpected '{}'", c))),
+        }
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError::new(message, self.pos)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected '{}', found '{}'", expected, c))),
+            None => Err(self.error(format!("expected '{}', found end of input", expected))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.skip_whitespace();
+        let position = self.pos;
+        let value = match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                let mut fields = vec![];
+                self.skip_whitespace();
+                if self.peek() == Some('}') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string()?;
+                        self.expect(':')?;
+                        fields.push((key, self.value()?));
+                        self.skip_whitespace();
+                        if self.peek() == Some(',') {
+                            self.pos += 1;
+                        } else {
+                            self.expect('}')?;
+                            break;
+                        }
+                    }
+                }
+                JsonValue::Object(fields)
+            }
+            Some('[') => {
+                self.pos += 1;
+                let mut items = vec![];
+                self.skip_whitespace();
+                if self.peek() == Some(']') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        items.push(self.value()?);
+                        self.skip_whitespace();
+                        if self.peek() == Some(',') {
+                            self.pos += 1;
+                        } else {
+                            self.expect(']')?;
+                            break;
+                        }
+                    }
+                }
+                JsonValue::Array(items)
+            }
+            Some('"') => JsonValue::String(self.string()?),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+                {
+                    self.pos += 1;
+                }
+                let text = &self.src[start..self.pos];
+                let n = text
+                    .parse()
+                    .map_err(|_| ParseError::new(format!("invalid number {}", text), start))?;
+                JsonValue::Number(n)
+            }
+            Some(_) if self.keyword("null") => JsonValue::Null,
+            Some(_) if self.keyword("true") || self.keyword("false") => JsonValue::Bool,
+            Some(c) => return Err(self.error(format!("unexpected '{}'", c))),
+            None => return Err(self.error("unexpected end of input".into())),
+        };
+        Ok(Json { value, position })
+    }
+
+    fn keyword(&mut self, word: &str) -> bool {
+        if self.src[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error("unterminated string".into()))?;
+            match c {
+                '"' => return Ok(s),
+                '\\' => {
+                    let escaped = self
+                        .bump()
+                        .ok_or_else(|| self.error("unterminated string".into()))?;
+                    s.push(match escaped {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'u' => {
+                            let hex = self.src.get(self.pos..self.pos + 4).unwrap_or("");
+                            let c = u32::from_str_radix(hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error(format!("invalid escape \\u{}", hex)))?;
+                            self.pos += 4;
+                            c
+                        }
+                        c => return Err(self.error(format!("invalid escape \\{}", c))),
+                    });
+                }
+                c => s.push(c),
+            }
+        }
+    }
+}
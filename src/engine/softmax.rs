@@ -0,0 +1,33 @@
+use super::{Float, Operation, Tensor};
+
+// 입력 전체에 대한 logsumexp 노드 하나를 출력끼리 공유하고, 출력 i는 x_i와 그 노드만 가리킴
+// s_i = exp(x_i - lse) 이므로 출력 하나의 값과 미분은 O(1), 공유 노드의 미분은 한 번만 O(n)
+// exp, Sum, pow(-1) 로 만들 때와 달리 최댓값을 빼고 계산하므로 큰 logit에서도 overflow 되지 않음
+pub fn softmax<T: Float>(xs: &[Tensor<T>]) -> Vec<Tensor<T>> {
+    assert!(!xs.is_empty(), "softmax requires at least one input");
+    project(xs, Operation::Softmax)
+}
+
+// x_i - logsumexp(x), 분류에서는 -log_softmax(logits)[target] 이 cross entropy
+pub fn log_softmax<T: Float>(xs: &[Tensor<T>]) -> Vec<Tensor<T>> {
+    assert!(!xs.is_empty(), "log_softmax requires at least one input");
+    project(xs, Operation::LogSoftmax)
+}
+
+// log(sum(exp(x))) = m + log(sum(exp(x - m))), m = max(x)
+pub fn logsumexp<T: Float>(xs: &[Tensor<T>]) -> Tensor<T> {
+    assert!(!xs.is_empty(), "logsumexp requires at least one input");
+    Tensor::from_operation(Operation::LogSumExp, xs.to_vec())
+}
+
+fn project<T: Float>(xs: &[Tensor<T>], operation: Operation) -> Vec<Tensor<T>> {
+    let lse = logsumexp(xs);
+    xs.iter()
+        .map(|x| Tensor::from_operation(operation, vec![x.clone(), lse.clone()]))
+        .collect()
+}
+
+pub(super) fn logsumexp_value<T: Float>(xs: &[T]) -> T {
+    let m = xs.iter().copied().fold(xs[0], T::max);
+    m + xs.iter().map(|x| (*x - m).exp()).sum::<T>().ln()
+}
@@ -0,0 +1,307 @@
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// Tensor 대신 하나의 연속된 배열(tape)에 노드를 쌓는 그래프 표현
+// 노드는 만들어진 순서대로 저장되므로 인덱스 순서가 곧 위상 정렬 순서
+// backward는 HashSet이나 재귀 없이 tape를 뒤에서부터 한 번 훑으면 끝남
+// 노드마다 Rc, RefCell, Vec, String을 할당하지 않으므로 학습 루프에서 훨씬 빠름
+//
+// 학습 루프에서는 파라미터를 f64로 들고 있다가 매 step마다 clear 후 다시 올림
+// (clear는 할당된 메모리를 그대로 재사용)
+#[derive(Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>,
+    grads: RefCell<Vec<f64>>,
+}
+
+// 부모는 최대 2개, 각 부모에 대한 국소 미분값을 forward 시점에 저장
+// 부모가 하나뿐이거나 leaf인 경우 남는 자리의 미분값은 0
+#[derive(Clone, Copy)]
+struct Node {
+    value: f64,
+    parents: [usize; 2],
+    partials: [f64; 2],
+}
+
+// tape 위의 노드를 가리키는 핸들 (Copy)
+#[derive(Clone, Copy)]
+pub struct Var<'t> {
+    tape: &'t Tape,
+    index: usize,
+}
+
+impl Tape {
+    pub fn new() -> Self {
+        Tape::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Tape {
+            nodes: RefCell::new(Vec::with_capacity(capacity)),
+            grads: RefCell::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    // leaf 노드
+    pub fn var(&self, value: f64) -> Var<'_> {
+        let index = self.nodes.borrow().len();
+        self.push(value, [index, index], [0.0, 0.0])
+    }
+
+    pub fn vars(&self, values: &[f64]) -> Vec<Var<'_>> {
+        values.iter().map(|v| self.var(*v)).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // 모든 노드를 지우되 할당된 메모리는 유지
+    // &mut self 이므로 기존 Var가 남아 있으면 호출할 수 없음
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+        self.grads.get_mut().clear();
+    }
+
+    fn push(&self, value: f64, parents: [usize; 2], partials: [f64; 2]) -> Var<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node {
+            value,
+            parents,
+            partials,
+        });
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+        }
+    }
+
+    // root에서부터 인덱스 역순으로 한 번에 훑음
+    fn backward(&self, root: usize) {
+        let nodes = self.nodes.borrow();
+        let mut grads = self.grads.borrow_mut();
+        grads.clear();
+        grads.resize(nodes.len(), 0.0);
+        grads[root] = 1.0;
+
+        for i in (0..=root).rev() {
+            let g = grads[i];
+            if g == 0.0 {
+                continue;
+            }
+            let node = &nodes[i];
+            for k in 0..2 {
+                grads[node.parents[k]] += node.partials[k] * g;
+            }
+        }
+    }
+}
+
+impl<'t> Var<'t> {
+    pub fn data(&self) -> f64 {
+        self.tape.nodes.borrow()[self.index].value
+    }
+
+    // 마지막 backward 기준의 기울기 (backward 전이면 0)
+    pub fn grad(&self) -> f64 {
+        self.tape
+            .grads
+            .borrow()
+            .get(self.index)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn backward(&self) {
+        self.tape.backward(self.index);
+    }
+
+    fn unary(&self, value: f64, partial: f64) -> Var<'t> {
+        self.tape
+            .push(value, [self.index, self.index], [partial, 0.0])
+    }
+
+    fn binary(&self, rhs: &Var<'t>, value: f64, partials: [f64; 2]) -> Var<'t> {
+        assert!(
+            std::ptr::eq(self.tape, rhs.tape),
+            "Vars from different tapes"
+        );
+        self.tape.push(value, [self.index, rhs.index], partials)
+    }
+
+    pub fn pow(&self, rhs: f64) -> Var<'t> {
+        let x = self.data();
+        self.unary(x.powf(rhs), rhs * x.powf(rhs - 1.0))
+    }
+
+    pub fn exp(&self) -> Var<'t> {
+        let e = self.data().exp();
+        self.unary(e, e)
+    }
+
+    pub fn log(&self) -> Var<'t> {
+        let x = self.data();
+        self.unary(x.ln(), 1.0 / x)
+    }
+
+    pub fn sqrt(&self) -> Var<'t> {
+        let r = self.data().sqrt();
+        self.unary(r, 0.5 / r)
+    }
+
+    pub fn tanh(&self) -> Var<'t> {
+        let t = self.data().tanh();
+        self.unary(t, 1.0 - t * t)
+    }
+
+    pub fn relu(&self) -> Var<'t> {
+        let x = self.data();
+        self.unary(x.max(0.0), if x > 0.0 { 1.0 } else { 0.0 })
+    }
+
+    pub fn sigmoid(&self) -> Var<'t> {
+        let s = 1.0 / (1.0 + (-self.data()).exp());
+        self.unary(s, s * (1.0 - s))
+    }
+
+    pub fn sin(&self) -> Var<'t> {
+        let x = self.data();
+        self.unary(x.sin(), x.cos())
+    }
+
+    pub fn cos(&self) -> Var<'t> {
+        let x = self.data();
+        self.unary(x.cos(), -x.sin())
+    }
+}
+
+impl Debug for Var<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Var {{ #{} | data: {:.4} | grad: {:.4} }}",
+            self.index,
+            self.data(),
+            self.grad()
+        )
+    }
+}
+
+impl<'t> Add for Var<'t> {
+    type Output = Var<'t>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.binary(&rhs, self.data() + rhs.data(), [1.0, 1.0])
+    }
+}
+
+impl<'t> Sub for Var<'t> {
+    type Output = Var<'t>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.binary(&rhs, self.data() - rhs.data(), [1.0, -1.0])
+    }
+}
+
+impl<'t> Mul for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (l, r) = (self.data(), rhs.data());
+        self.binary(&rhs, l * r, [r, l])
+    }
+}
+
+impl<'t> Div for Var<'t> {
+    type Output = Var<'t>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (l, r) = (self.data(), rhs.data());
+        self.binary(&rhs, l / r, [1.0 / r, -l / (r * r)])
+    }
+}
+
+impl<'t> Neg for Var<'t> {
+    type Output = Var<'t>;
+
+    fn neg(self) -> Self::Output {
+        self.unary(-self.data(), -1.0)
+    }
+}
+
+// 상수는 노드를 만들지 않고 국소 미분값에만 반영
+impl<'t> Add<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        self.unary(self.data() + rhs, 1.0)
+    }
+}
+
+impl<'t> Sub<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.unary(self.data() - rhs, 1.0)
+    }
+}
+
+impl<'t> Mul<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.unary(self.data() * rhs, rhs)
+    }
+}
+
+impl<'t> Div<f64> for Var<'t> {
+    type Output = Var<'t>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.unary(self.data() / rhs, 1.0 / rhs)
+    }
+}
+
+impl<'t> Add<Var<'t>> for f64 {
+    type Output = Var<'t>;
+
+    fn add(self, rhs: Var<'t>) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<'t> Sub<Var<'t>> for f64 {
+    type Output = Var<'t>;
+
+    fn sub(self, rhs: Var<'t>) -> Self::Output {
+        rhs.unary(self - rhs.data(), -1.0)
+    }
+}
+
+impl<'t> Mul<Var<'t>> for f64 {
+    type Output = Var<'t>;
+
+    fn mul(self, rhs: Var<'t>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<'t> Div<Var<'t>> for f64 {
+    type Output = Var<'t>;
+
+    fn div(self, rhs: Var<'t>) -> Self::Output {
+        let r = rhs.data();
+        rhs.unary(self / r, -self / (r * r))
+    }
+}
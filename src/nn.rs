@@ -2,100 +2,148 @@ use std::{cell::RefCell, hash::Hash, iter::zip, rc::Rc};
 
 use rand::Rng;
 
-use crate::engine::Tensor;
+use crate::engine::{Float, Tensor};
 
-pub struct NeuronData {
-    weights: Vec<Tensor>,
-    bias: Tensor,
+// forward가 끝난 뒤 (입력, 출력)으로 호출, activation 기록용
+type NeuronHook<T> = Rc<dyn Fn(&[Tensor<T>], &Tensor<T>)>;
+type LayerHook<T> = Rc<dyn Fn(&[Tensor<T>], &[Tensor<T>])>;
+
+pub struct NeuronData<T: Float> {
+    weights: Vec<Tensor<T>>,
+    bias: Tensor<T>,
+    hooks: Vec<NeuronHook<T>>,
 }
 
 #[derive(Clone)]
-pub struct Neuron(Rc<RefCell<NeuronData>>);
+pub struct Neuron<T: Float = f64>(Rc<RefCell<NeuronData<T>>>);
+
+// -1.0 ~ 1.0 균등 분포로 초기화
+fn random<T: Float>(rng: &mut impl Rng) -> Tensor<T> {
+    Tensor::leaf(T::from_f64(rng.random_range(-1.0..1.0)))
+}
 
-impl Neuron {
+impl<T: Float> Neuron<T> {
     pub fn new(n: usize) -> Self {
         let mut rng = rand::rng();
-        let weights = (0..n)
-            .map(|_| Tensor::new(rng.random_range(-1.0..1.0)))
-            .collect();
+        let weights = (0..n).map(|_| random(&mut rng)).collect();
 
         Self(Rc::new(RefCell::new(NeuronData {
             weights,
-            bias: Tensor::new(rng.random_range(-1.0..1.0)),
+            bias: random(&mut rng),
+            hooks: vec![],
         })))
     }
 
-    pub fn weights(&self) -> Vec<Tensor> {
+    pub fn weights(&self) -> Vec<Tensor<T>> {
         self.0.borrow().weights.clone()
     }
 
-    pub fn bias(&self) -> Tensor {
+    pub fn bias(&self) -> Tensor<T> {
         self.0.borrow().bias.clone()
     }
 
-    pub fn parameters(&self) -> Vec<Tensor> {
+    pub fn parameters(&self) -> Vec<Tensor<T>> {
         [self.weights(), vec![self.bias()]].concat()
     }
 
-    pub fn forward(&self, x: &Vec<Tensor>) -> Tensor {
-        let data = zip(self.weights(), x).map(|(w, x)| w * x).sum::<Tensor>() + self.bias();
+    #[allow(clippy::ptr_arg)]
+    pub fn forward(&self, x: &Vec<Tensor<T>>) -> Tensor<T> {
+        self.activate(x, &Tensor::dot(&self.weights(), x))
+    }
+
+    // w·x 다음 계산 (bias, tanh, hook), Layer::forward 에서는 w·x 를 층 전체의 matvec으로 구함
+    fn activate(&self, x: &[Tensor<T>], wx: &Tensor<T>) -> Tensor<T> {
+        let out = (wx + &self.bias()).tanh();
+
+        let hooks = self.0.borrow().hooks.clone();
+        for hook in hooks {
+            hook(x, &out);
+        }
+        out
+    }
+
+    pub fn register_forward_hook(&self, hook: impl Fn(&[Tensor<T>], &Tensor<T>) + 'static) {
+        self.0.borrow_mut().hooks.push(Rc::new(hook));
+    }
 
-        data.tanh()
+    pub fn clear_forward_hooks(&self) {
+        self.0.borrow_mut().hooks.clear();
     }
 }
 
-impl PartialEq for Neuron {
+impl<T: Float> PartialEq for Neuron<T> {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
-impl Eq for Neuron {}
+impl<T: Float> Eq for Neuron<T> {}
 
-impl Hash for Neuron {
+impl<T: Float> Hash for Neuron<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.0.as_ptr().hash(state);
     }
 }
 
-pub struct LayerData {
-    neurons: Vec<Neuron>,
+pub struct LayerData<T: Float> {
+    neurons: Vec<Neuron<T>>,
+    hooks: Vec<LayerHook<T>>,
 }
 
 #[derive(Clone)]
-pub struct Layer(Rc<RefCell<LayerData>>);
+pub struct Layer<T: Float = f64>(Rc<RefCell<LayerData<T>>>);
 
-impl Layer {
+impl<T: Float> Layer<T> {
     pub fn new(n_in: usize, n_out: usize) -> Self {
         let neurons = (0..n_out).map(|_| Neuron::new(n_in)).collect();
-        Self(Rc::new(RefCell::new(LayerData { neurons })))
+        Self(Rc::new(RefCell::new(LayerData {
+            neurons,
+            hooks: vec![],
+        })))
     }
 
-    pub fn neurons(&self) -> Vec<Neuron> {
+    pub fn neurons(&self) -> Vec<Neuron<T>> {
         self.0.borrow().neurons.clone()
     }
 
-    pub fn parameters(&self) -> Vec<Tensor> {
-        self.neurons()
-            .iter()
-            .map(|n| n.parameters())
-            .flatten()
-            .collect()
+    pub fn parameters(&self) -> Vec<Tensor<T>> {
+        self.neurons().iter().flat_map(|n| n.parameters()).collect()
     }
 
-    pub fn forward(&self, x: &Vec<Tensor>) -> Vec<Tensor> {
-        self.neurons().iter().map(|n| n.forward(&x)).collect()
+    // 뉴런들의 weight를 행렬로 모아서 w x 를 노드 하나로 계산 (backward도 한 번에)
+    #[allow(clippy::ptr_arg)]
+    pub fn forward(&self, x: &Vec<Tensor<T>>) -> Vec<Tensor<T>> {
+        let neurons = self.neurons();
+        let w: Vec<Vec<Tensor<T>>> = neurons.iter().map(|n| n.weights()).collect();
+        let out: Vec<Tensor<T>> = zip(&neurons, Tensor::matvec(&w, x))
+            .map(|(n, wx)| n.activate(x, &wx))
+            .collect();
+
+        let hooks = self.0.borrow().hooks.clone();
+        for hook in hooks {
+            hook(x, &out);
+        }
+        out
+    }
+
+    pub fn register_forward_hook(&self, hook: impl Fn(&[Tensor<T>], &[Tensor<T>]) + 'static) {
+        self.0.borrow_mut().hooks.push(Rc::new(hook));
+    }
+
+    pub fn clear_forward_hooks(&self) {
+        self.0.borrow_mut().hooks.clear();
     }
 }
 
-pub struct MLPData {
-    layers: Vec<Layer>,
+pub struct MLPData<T: Float> {
+    layers: Vec<Layer<T>>,
+    hooks: Vec<LayerHook<T>>,
 }
 
 #[derive(Clone)]
-pub struct MLP(Rc<RefCell<MLPData>>);
+pub struct MLP<T: Float = f64>(Rc<RefCell<MLPData<T>>>);
 
-impl MLP {
+impl<T: Float> MLP<T> {
     pub fn new(n_in: usize, n_outs: Vec<usize>) -> Self {
         let nodes = [vec![n_in], n_outs].concat();
         let mut layers = Vec::new();
@@ -103,21 +151,41 @@ impl MLP {
             layers.push(Layer::new(nodes[i], nodes[i + 1]));
         }
 
-        Self(Rc::new(RefCell::new(MLPData { layers })))
+        Self(Rc::new(RefCell::new(MLPData {
+            layers,
+            hooks: vec![],
+        })))
     }
 
-    pub fn layers(&self) -> Vec<Layer> {
+    pub fn layers(&self) -> Vec<Layer<T>> {
         self.0.borrow().layers.clone()
     }
 
-    pub fn parameters(&self) -> Vec<Vec<Tensor>> {
+    pub fn parameters(&self) -> Vec<Vec<Tensor<T>>> {
         self.layers().iter().map(|l| l.parameters()).collect()
     }
 
-    pub fn forward(&self, x: &Vec<Tensor>) -> Vec<Tensor> {
-        self.layers()
+    #[allow(clippy::ptr_arg)]
+    pub fn forward(&self, x: &Vec<Tensor<T>>) -> Vec<Tensor<T>> {
+        let out = self
+            .layers()
             .iter()
-            .fold(x.clone(), |acc, l| l.forward(&acc))
+            .fold(x.clone(), |acc, l| l.forward(&acc));
+
+        let hooks = self.0.borrow().hooks.clone();
+        for hook in hooks {
+            hook(x, &out);
+        }
+        out
+    }
+
+    // 각 층의 activation은 layers()의 Layer마다 등록
+    pub fn register_forward_hook(&self, hook: impl Fn(&[Tensor<T>], &[Tensor<T>]) + 'static) {
+        self.0.borrow_mut().hooks.push(Rc::new(hook));
+    }
+
+    pub fn clear_forward_hooks(&self) {
+        self.0.borrow_mut().hooks.clear();
     }
 }
 
@@ -125,19 +193,19 @@ pub trait Module {
     fn zero_grad(&self);
 }
 
-impl Module for MLP {
+impl<T: Float> Module for MLP<T> {
     fn zero_grad(&self) {
         let _ = self.layers().iter().map(|l| l.zero_grad());
     }
 }
-impl Module for Layer {
+impl<T: Float> Module for Layer<T> {
     fn zero_grad(&self) {
         let _ = self.neurons().iter().map(|n| n.zero_grad());
     }
 }
 
-impl Module for Neuron {
+impl<T: Float> Module for Neuron<T> {
     fn zero_grad(&self) {
-        let _ = self.parameters().iter().map(|p| p.set_grad(0.0));
+        let _ = self.parameters().iter().map(|p| p.set_grad(T::zero()));
     }
 }
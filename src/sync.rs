@@ -0,0 +1,511 @@
+// engine::Tensor / nn과 같은 구조의 thread-safe 버전
+// Rc<RefCell<..>> 대신 Arc와 atomic을 사용하므로 Send + Sync
+// 파라미터는 여러 thread에서 공유하고, 각 thread는 자기 sample의 그래프를 만들어 backward
+// 공유 파라미터의 기울기는 atomic하게 누적되므로 별도의 reduce 없이 합쳐짐
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use rand::Rng;
+
+// f64를 bit 단위로 담는 atomic
+struct AtomicF64(AtomicU64);
+
+impl AtomicF64 {
+    fn new(value: f64) -> Self {
+        AtomicF64(AtomicU64::new(value.to_bits()))
+    }
+
+    fn load(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Acquire))
+    }
+
+    fn store(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Release);
+    }
+
+    fn fetch_add(&self, value: f64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |bits| {
+                Some((f64::from_bits(bits) + value).to_bits())
+            });
+    }
+}
+
+struct TensorData {
+    data: AtomicF64,
+    grad: AtomicF64,
+    label: Mutex<String>,
+
+    // 생성 이후 바뀌지 않으므로 lock 없이 읽음
+    _backward: Option<fn(&Tensor)>,
+    _prev: Vec<Tensor>,
+    // 연산에 필요한 상수 (pow의 지수)
+    _arg: f64,
+}
+
+// engine::TensorData와 같은 이유로 반복문으로 해제
+impl Drop for TensorData {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self._prev);
+        while let Some(tensor) = stack.pop() {
+            if let Some(mut data) = Arc::into_inner(tensor.0) {
+                stack.append(&mut data._prev);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Tensor(Arc<TensorData>);
+
+impl PartialEq for Tensor {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Tensor {}
+
+impl Hash for Tensor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl Tensor {
+    pub fn new(data: f64) -> Self {
+        Tensor(Arc::new(TensorData {
+            data: AtomicF64::new(data),
+            grad: AtomicF64::new(0.0),
+            label: Mutex::new(String::new()),
+            _backward: None,
+            _prev: vec![],
+            _arg: 0.0,
+        }))
+    }
+
+    pub fn new_with_label(data: f64, label: &str) -> Self {
+        let tensor = Self::new(data);
+        tensor.set_label(label);
+        tensor
+    }
+
+    fn new_with_operation(data: f64, prev: Vec<Tensor>, backward: fn(&Tensor)) -> Self {
+        Self::new_with_arg(data, prev, backward, 0.0)
+    }
+
+    fn new_with_arg(data: f64, prev: Vec<Tensor>, backward: fn(&Tensor), arg: f64) -> Self {
+        Tensor(Arc::new(TensorData {
+            data: AtomicF64::new(data),
+            grad: AtomicF64::new(0.0),
+            label: Mutex::new(String::new()),
+            _backward: Some(backward),
+            _prev: prev,
+            _arg: arg,
+        }))
+    }
+
+    pub fn from_vec(datas: Vec<f64>) -> Vec<Self> {
+        datas.iter().map(|d| Tensor::new(*d)).collect()
+    }
+
+    // 데이터 조회
+    pub fn data(&self) -> f64 {
+        self.0.data.load()
+    }
+    pub fn grad(&self) -> f64 {
+        self.0.grad.load()
+    }
+    pub fn prev(&self) -> &[Tensor] {
+        &self.0._prev
+    }
+    pub fn label(&self) -> String {
+        self.0.label.lock().unwrap().clone()
+    }
+    pub fn is_leaf(&self) -> bool {
+        self.0._prev.is_empty()
+    }
+
+    // setter
+    pub fn set_data(&self, data: f64) {
+        self.0.data.store(data);
+    }
+    pub fn set_grad(&self, grad: f64) {
+        self.0.grad.store(grad);
+    }
+    pub fn set_label(&self, label: &str) {
+        *self.0.label.lock().unwrap() = label.into();
+    }
+
+    // 다른 thread가 같은 노드에 동시에 누적해도 안전
+    fn add_grad(&self, grad: f64) {
+        self.0.grad.fetch_add(grad);
+    }
+
+    // leaf(공유 파라미터)의 기울기는 누적, 중간 노드의 기울기는 호출할 때마다 0에서 다시 계산
+    // (engine::Tensor::backward는 중간 노드의 기울기도 이전 backward 값에 누적함)
+    pub fn backward(&self) {
+        let mut todos = self.topological_sort();
+        todos.reverse();
+
+        for node in &todos {
+            if !node.is_leaf() {
+                node.set_grad(0.0);
+            }
+        }
+        self.set_grad(1.0);
+
+        for node in &todos {
+            if let Some(f) = node.0._backward {
+                f(node);
+            }
+        }
+    }
+
+    #[allow(clippy::mutable_key_type)]
+    pub fn topological_sort(&self) -> Vec<Tensor> {
+        let mut visited = HashSet::new();
+        let mut todo = Vec::new();
+
+        let mut stack = vec![(self.clone(), false)];
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                todo.push(node);
+                continue;
+            }
+            if !visited.insert(node.clone()) {
+                continue;
+            }
+
+            stack.push((node.clone(), true));
+            for p in node.prev().iter().rev() {
+                if !visited.contains(p) {
+                    stack.push((p.clone(), false));
+                }
+            }
+        }
+
+        todo
+    }
+
+    pub fn tanh(&self) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let x = &out.prev()[0];
+            x.add_grad((1.0 - out.data().powi(2)) * out.grad());
+        }
+
+        Tensor::new_with_operation(self.data().tanh(), vec![self.clone()], _backward)
+    }
+
+    pub fn exp(&self) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let x = &out.prev()[0];
+            x.add_grad(out.data() * out.grad());
+        }
+
+        Tensor::new_with_operation(self.data().exp(), vec![self.clone()], _backward)
+    }
+
+    pub fn relu(&self) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let x = &out.prev()[0];
+            if x.data() > 0.0 {
+                x.add_grad(out.grad());
+            }
+        }
+
+        Tensor::new_with_operation(self.data().max(0.0), vec![self.clone()], _backward)
+    }
+
+    pub fn sigmoid(&self) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let x = &out.prev()[0];
+            let s = out.data();
+            x.add_grad(s * (1.0 - s) * out.grad());
+        }
+
+        let s = 1.0 / (1.0 + (-self.data()).exp());
+        Tensor::new_with_operation(s, vec![self.clone()], _backward)
+    }
+
+    pub fn log(&self) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let x = &out.prev()[0];
+            x.add_grad(out.grad() / x.data());
+        }
+
+        Tensor::new_with_operation(self.data().ln(), vec![self.clone()], _backward)
+    }
+
+    // 지수는 상수, 밑의 기울기만 계산
+    pub fn pow(&self, rhs: f64) -> Tensor {
+        fn _backward(out: &Tensor) {
+            let (x, n) = (&out.prev()[0], out.0._arg);
+            x.add_grad(n * x.data().powf(n - 1.0) * out.grad());
+        }
+
+        Tensor::new_with_arg(self.data().powf(rhs), vec![self.clone()], _backward, rhs)
+    }
+}
+
+impl Debug for Tensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Tensor {{ {} | data: {:.4} | grad: {:.4} }}",
+            self.label(),
+            self.data(),
+            self.grad()
+        )
+    }
+}
+
+impl Add for &Tensor {
+    type Output = Tensor;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        fn _backward(out: &Tensor) {
+            for p in out.prev() {
+                p.add_grad(out.grad());
+            }
+        }
+
+        Tensor::new_with_operation(
+            self.data() + rhs.data(),
+            vec![self.clone(), rhs.clone()],
+            _backward,
+        )
+    }
+}
+
+impl Mul for &Tensor {
+    type Output = Tensor;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        fn _backward(out: &Tensor) {
+            let prev = out.prev();
+            let l = &prev[0];
+            let r = &prev[1];
+            l.add_grad(r.data() * out.grad());
+            r.add_grad(l.data() * out.grad());
+        }
+
+        Tensor::new_with_operation(
+            self.data() * rhs.data(),
+            vec![self.clone(), rhs.clone()],
+            _backward,
+        )
+    }
+}
+
+impl Neg for &Tensor {
+    type Output = Tensor;
+
+    fn neg(self) -> Self::Output {
+        fn _backward(out: &Tensor) {
+            out.prev()[0].add_grad(-out.grad());
+        }
+
+        Tensor::new_with_operation(-self.data(), vec![self.clone()], _backward)
+    }
+}
+
+impl Sub for &Tensor {
+    type Output = Tensor;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Div for &Tensor {
+    type Output = Tensor;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * &rhs.pow(-1.0)
+    }
+}
+
+// 값으로 받는 경우와 f64와의 연산은 참조 구현을 재사용
+macro_rules! impl_ops {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for Tensor {
+                type Output = Tensor;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&Tensor> for Tensor {
+                type Output = Tensor;
+
+                fn $method(self, rhs: &Tensor) -> Self::Output {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<Tensor> for &Tensor {
+                type Output = Tensor;
+
+                fn $method(self, rhs: Tensor) -> Self::Output {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $trait<f64> for &Tensor {
+                type Output = Tensor;
+
+                fn $method(self, rhs: f64) -> Self::Output {
+                    self.$method(&Tensor::new(rhs))
+                }
+            }
+
+            impl $trait<f64> for Tensor {
+                type Output = Tensor;
+
+                fn $method(self, rhs: f64) -> Self::Output {
+                    (&self).$method(&Tensor::new(rhs))
+                }
+            }
+
+            impl $trait<&Tensor> for f64 {
+                type Output = Tensor;
+
+                fn $method(self, rhs: &Tensor) -> Self::Output {
+                    (&Tensor::new(self)).$method(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_ops!(Add add, Mul mul, Sub sub, Div div);
+
+impl Sum for Tensor {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Tensor::new(0.0), |acc, x| &acc + &x)
+    }
+}
+
+// ---- nn
+// 파라미터 Tensor가 이미 thread-safe 하므로 구조는 Arc로 공유만 함
+
+struct NeuronData {
+    weights: Vec<Tensor>,
+    bias: Tensor,
+}
+
+#[derive(Clone)]
+pub struct Neuron(Arc<NeuronData>);
+
+impl Neuron {
+    pub fn new(n: usize) -> Self {
+        let mut rng = rand::rng();
+        let weights = (0..n)
+            .map(|_| Tensor::new(rng.random_range(-1.0..1.0)))
+            .collect();
+
+        Self(Arc::new(NeuronData {
+            weights,
+            bias: Tensor::new(rng.random_range(-1.0..1.0)),
+        }))
+    }
+
+    pub fn weights(&self) -> &[Tensor] {
+        &self.0.weights
+    }
+
+    pub fn bias(&self) -> &Tensor {
+        &self.0.bias
+    }
+
+    pub fn parameters(&self) -> Vec<Tensor> {
+        [self.weights(), &[self.bias().clone()]].concat()
+    }
+
+    pub fn forward(&self, x: &[Tensor]) -> Tensor {
+        let data = self
+            .weights()
+            .iter()
+            .zip(x)
+            .map(|(w, x)| w * x)
+            .sum::<Tensor>()
+            + self.bias();
+
+        data.tanh()
+    }
+}
+
+struct LayerData {
+    neurons: Vec<Neuron>,
+}
+
+#[derive(Clone)]
+pub struct Layer(Arc<LayerData>);
+
+impl Layer {
+    pub fn new(n_in: usize, n_out: usize) -> Self {
+        let neurons = (0..n_out).map(|_| Neuron::new(n_in)).collect();
+        Self(Arc::new(LayerData { neurons }))
+    }
+
+    pub fn neurons(&self) -> &[Neuron] {
+        &self.0.neurons
+    }
+
+    pub fn parameters(&self) -> Vec<Tensor> {
+        self.neurons().iter().flat_map(|n| n.parameters()).collect()
+    }
+
+    pub fn forward(&self, x: &[Tensor]) -> Vec<Tensor> {
+        self.neurons().iter().map(|n| n.forward(x)).collect()
+    }
+}
+
+struct MLPData {
+    layers: Vec<Layer>,
+}
+
+#[derive(Clone)]
+pub struct MLP(Arc<MLPData>);
+
+impl MLP {
+    pub fn new(n_in: usize, n_outs: Vec<usize>) -> Self {
+        let nodes = [vec![n_in], n_outs].concat();
+        let layers = nodes.windows(2).map(|n| Layer::new(n[0], n[1])).collect();
+
+        Self(Arc::new(MLPData { layers }))
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.0.layers
+    }
+
+    pub fn parameters(&self) -> Vec<Vec<Tensor>> {
+        self.layers().iter().map(|l| l.parameters()).collect()
+    }
+
+    pub fn forward(&self, x: &[Tensor]) -> Vec<Tensor> {
+        self.layers()
+            .iter()
+            .fold(x.to_vec(), |acc, l| l.forward(&acc))
+    }
+
+    pub fn zero_grad(&self) {
+        for p in self.parameters().iter().flatten() {
+            p.set_grad(0.0);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use rust_micrograd::engine::{Tensor, gradcheck};
+
+// 중앙 차분으로 구한 기울기와 backward 결과 비교
+fn check(f: fn(&Tensor) -> Tensor, xs: &[f64]) {
+    for &x in xs {
+        let report = gradcheck(|t| f(&t[0]), &[Tensor::new_with_label(x, "a")], 1e-6, 1e-5);
+        println!("{}", report);
+        assert!(report.passed(), "x = {}", x);
+    }
+}
+
+const XS: [f64; 6] = [-2.5, -0.7, -0.1, 0.3, 1.2, 3.0];
+
+#[test]
+fn test_relu() {
+    check(|a| a.relu(), &XS);
+
+    let a = Tensor::new(-1.0);
+    assert_eq!(a.relu().data(), 0.0);
+}
+
+#[test]
+fn test_leaky_relu() {
+    check(|a| a.leaky_relu(0.01), &XS);
+    assert_eq!(Tensor::new(-2.0).leaky_relu(0.1).data(), -0.2);
+}
+
+#[test]
+fn test_sigmoid() {
+    check(|a| a.sigmoid(), &XS);
+    assert_eq!(Tensor::new(0.0).sigmoid().data(), 0.5);
+    assert!(Tensor::new(-1000.0).sigmoid().data().is_finite());
+}
+
+#[test]
+fn test_log_sqrt() {
+    check(|a| a.log(), &[0.1, 0.5, 1.0, 4.0]);
+    check(|a| a.sqrt(), &[0.1, 0.5, 1.0, 4.0]);
+}
+
+#[test]
+fn test_abs() {
+    check(|a| a.abs(), &XS);
+
+    let a = Tensor::new(0.0);
+    a.abs().backward();
+    assert_eq!(a.grad(), 0.0);
+}
+
+#[test]
+fn test_trigonometric() {
+    check(|a| a.sin(), &XS);
+    check(|a| a.cos(), &XS);
+}
+
+#[test]
+fn test_smooth_activations() {
+    check(|a| a.softplus(), &XS);
+    check(|a| a.gelu(), &XS);
+    check(|a| a.silu(), &XS);
+    check(|a| a.elu(1.0), &XS);
+    check(|a| a.elu(0.5), &XS);
+
+    // overflow 없이 x에 수렴
+    assert_eq!(Tensor::new(1000.0).softplus().data(), 1000.0);
+}
+
+#[test]
+fn test_composition() {
+    // sigmoid(x) * log(x) + sin(x)
+    check(|a| &(&a.sigmoid() * &a.log()) + &a.sin(), &[0.2, 1.5, 3.0]);
+}
+
+#[test]
+fn test_pow() {
+    check(|a| a.pow(3.0), &XS);
+    check(|a| a.pow(-1.0), &XS);
+    check(|a| a.pow(0.5), &[0.1, 1.0, 4.0]);
+
+    // 상수 지수는 그래프에 들어가지 않음
+    let a = Tensor::new(2.0);
+    assert_eq!(a.pow(2.0).prev().len(), 1);
+}
+
+#[test]
+fn test_pow_tensor() {
+    let h = 1e-6;
+    let f = |x: f64, y: f64| x.powf(y);
+    for (x, y) in [(2.0, 3.0), (0.5, -1.5), (3.0, 0.5)] {
+        let a = Tensor::new_with_label(x, "a");
+        let b = Tensor::new_with_label(y, "b");
+        let c = a.pow(&b);
+        c.backward();
+        println!("{:?} {:?} {:?}", a, b, c);
+
+        let da = (f(x + h, y) - f(x - h, y)) / (2.0 * h);
+        let db = (f(x, y + h) - f(x, y - h)) / (2.0 * h);
+        assert!((a.grad() - da).abs() < 1e-5);
+        assert!((b.grad() - db).abs() < 1e-5);
+    }
+
+    // 밑이 0 이하이면 지수 쪽 기울기는 0
+    for x in [0.0, -2.0] {
+        let a = Tensor::new(x);
+        let b = Tensor::new(2.0);
+        let c = a.pow(&b);
+        c.backward();
+        assert_eq!(c.data(), x * x);
+        assert_eq!(a.grad(), 2.0 * x);
+        assert_eq!(b.grad(), 0.0);
+    }
+}
@@ -0,0 +1,132 @@
+use std::slice::from_ref;
+
+use rust_micrograd::engine::{CustomOp, Tensor, gradcheck, no_grad};
+
+// x^3
+struct Cube;
+
+impl CustomOp for Cube {
+    fn name(&self) -> &str {
+        "cube"
+    }
+
+    fn forward(&self, inputs: &[f64]) -> f64 {
+        inputs[0].powi(3)
+    }
+
+    fn backward(&self, inputs: &[f64], _output: f64, out_grad: f64) -> Vec<f64> {
+        vec![3.0 * inputs[0].powi(2) * out_grad]
+    }
+}
+
+// sqrt(x^2 + y^2), 출력 값을 backward에서 재사용
+struct Hypot;
+
+impl CustomOp for Hypot {
+    fn name(&self) -> &str {
+        "hypot"
+    }
+
+    fn forward(&self, inputs: &[f64]) -> f64 {
+        inputs[0].hypot(inputs[1])
+    }
+
+    fn backward(&self, inputs: &[f64], output: f64, out_grad: f64) -> Vec<f64> {
+        inputs.iter().map(|x| x / output * out_grad).collect()
+    }
+}
+
+// 이름 없이 기본값을 쓰는 f32 연산
+struct Square;
+
+impl CustomOp<f32> for Square {
+    fn forward(&self, inputs: &[f32]) -> f32 {
+        inputs[0] * inputs[0]
+    }
+
+    fn backward(&self, inputs: &[f32], _output: f32, out_grad: f32) -> Vec<f32> {
+        vec![2.0 * inputs[0] * out_grad]
+    }
+}
+
+#[test]
+fn test_apply() {
+    let x = Tensor::new_with_label(2.0, "x");
+    let y = Tensor::apply(Cube, from_ref(&x));
+    assert_eq!(y.data(), 8.0);
+    assert_eq!(y.prev(), vec![x.clone()]);
+
+    y.backward();
+    assert_eq!(x.grad(), 12.0);
+}
+
+#[test]
+fn test_gradcheck() {
+    let inputs = [
+        Tensor::new_with_label(3.0, "a"),
+        Tensor::new_with_label(-4.0, "b"),
+    ];
+    let report = gradcheck(
+        |t| {
+            let h = Tensor::apply(Hypot, t);
+            &Tensor::apply(Cube, &[h.tanh()]) * &t[0]
+        },
+        &inputs,
+        1e-6,
+        1e-5,
+    );
+    println!("{}", report);
+    assert!(report.passed());
+}
+
+#[test]
+fn test_shared_input() {
+    // 같은 Tensor를 두 입력으로 넣으면 기울기가 누적됨
+    let x = Tensor::new(3.0);
+    let y = Tensor::apply(Hypot, &[x.clone(), x.clone()]);
+    y.backward();
+    assert!((x.grad() - 2.0_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_draw() {
+    let x = Tensor::new_with_label(2.0, "x");
+    let y = Tensor::apply(Cube, &[x]);
+    y.set_label("y");
+    y.backward();
+
+    let dot = y.to_dot();
+    println!("{}", dot);
+    assert!(dot.contains("label=\"cube\""));
+    assert!(dot.contains("{ x | data 2.0000 | grad 12.0000 }"));
+    assert!(y.to_svg().contains(">cube</text>"));
+
+    let z = Tensor::apply(Square, &[Tensor::<f32>::leaf(3.0)]);
+    assert!(z.to_dot().contains("label=\"custom\""));
+}
+
+#[test]
+fn test_no_grad() {
+    let x = Tensor::new(2.0);
+    let y = no_grad(|| Tensor::apply(Cube, from_ref(&x)));
+    assert!(y.is_leaf());
+    assert_eq!(y.data(), 8.0);
+}
+
+#[test]
+#[should_panic(expected = "backward_create_graph cannot differentiate through custom op cube")]
+fn test_create_graph() {
+    // 기울기를 상수로 취급하면 2차 미분이 조용히 0이 되므로 거부
+    let x = Tensor::new(2.0);
+    let y = &Tensor::apply(Cube, from_ref(&x)) * &x;
+    y.backward_create_graph(from_ref(&x));
+}
+
+#[test]
+fn test_f32() {
+    let x = Tensor::<f32>::leaf(1.5);
+    let y = Tensor::apply(Square, from_ref(&x));
+    y.backward();
+    assert_eq!(y.data(), 2.25);
+    assert_eq!(x.grad(), 3.0);
+}
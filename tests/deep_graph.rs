@@ -0,0 +1,50 @@
+use rust_micrograd::engine::Tensor;
+
+const DEPTH: usize = 1_000_000;
+
+#[test]
+fn test_deep_chain() {
+    let x = Tensor::new_with_label(0.5, "x");
+
+    // y = x + 1 + 1 + ... (깊이 1,000,000, 재귀 정렬이면 stack overflow)
+    let mut y = x.clone();
+    for _ in 0..DEPTH {
+        y = &y + 1.0;
+    }
+    assert_eq!(y.data(), 0.5 + DEPTH as f64);
+
+    y.backward();
+    assert_eq!(x.grad(), 1.0);
+
+    // 그래프 해제도 재귀 없이 끝나야 함
+    drop(y);
+}
+
+#[test]
+fn test_deep_sum() {
+    let xs = Tensor::from_vec(vec![1.0; DEPTH]);
+    let total: Tensor = xs.iter().cloned().sum();
+    assert_eq!(total.data(), DEPTH as f64);
+
+    total.backward();
+    assert!(xs.iter().all(|x| x.grad() == 1.0));
+}
+
+#[test]
+fn test_topological_order() {
+    let a = Tensor::new_with_label(2.0, "a");
+    let b = Tensor::new_with_label(3.0, "b");
+    let c = &a * &b;
+    let d = &c + &a;
+    let e = d.tanh();
+
+    let order = e.topological_sort();
+    assert_eq!(order.len(), 5);
+    // 모든 노드는 자신의 부모보다 뒤에 위치
+    for (i, node) in order.iter().enumerate() {
+        for p in node.prev() {
+            let j = order.iter().position(|n| *n == p).unwrap();
+            assert!(j < i);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use rust_micrograd::engine::Tensor;
+
+fn neuron() -> Tensor {
+    let x1 = Tensor::new_with_label(2.0, "x1");
+    let w1 = Tensor::new_with_label(-3.0, "w1");
+    let b = Tensor::new_with_label(6.881373587019543, "b");
+    let x1w1 = &x1 * &w1;
+    x1w1.set_label("x1*w1");
+    let n = &x1w1 + &b;
+    n.set_label("n");
+    let o = n.tanh();
+    o.set_label("o");
+    o
+}
+
+#[test]
+fn test_to_dot() {
+    let o = neuron();
+    o.backward();
+
+    let dot = o.to_dot();
+    println!("{}", dot);
+
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.contains("rankdir=LR"));
+    // 값 노드 6개, 연산 노드 3개
+    assert_eq!(dot.matches("shape=record").count(), 6);
+    assert_eq!(dot.matches("_op [label=").count(), 3);
+    assert!(dot.contains("label=\"tanh\""));
+    assert!(dot.contains("{ o | data 0.7071 | grad 1.0000 }"));
+    assert!(dot.contains("{ x1*w1 | data -6.0000 | grad 0.5000 }"));
+    // 연산 노드로 들어가는 간선 5개, 연산에서 나가는 간선 3개
+    assert_eq!(dot.matches("->").count(), 8);
+}
+
+#[test]
+fn test_to_svg() {
+    let o = neuron();
+    o.backward();
+
+    let svg = o.to_svg();
+    println!("{}", svg);
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("<rect").count(), 6);
+    assert_eq!(svg.matches("<circle").count(), 3);
+    assert!(svg.contains("o | data 0.7071 | grad 1.0000"));
+}
+
+#[test]
+fn test_escape() {
+    let a = Tensor::new_with_label(1.0, "a<b>\"c\"");
+    let dot = a.to_dot();
+    assert!(dot.contains("a\\<b\\>\\\"c\\\""));
+    let svg = a.to_svg();
+    assert!(svg.contains("a&lt;b&gt;&quot;c&quot;"));
+}
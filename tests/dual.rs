@@ -0,0 +1,91 @@
+use rust_micrograd::engine::{Dual, Tensor, jvp};
+
+#[test]
+fn test_dual() {
+    // f(x) = 3x^2 - 4x + 5, f'(x) = 6x - 4
+    let x = Dual::variable(-3.0);
+    let f = 3.0 * x.pow(2.0) - 4.0 * x + 5.0;
+    println!("f: {:?}", f);
+    assert_eq!(f.value, 44.0);
+    assert_eq!(f.tangent, -22.0);
+}
+
+#[test]
+fn test_forward_matches_reverse() {
+    // o = tanh(x1*w1 + x2*w2 + b) / exp(w2) - (x1 - 1)^w1
+    let values = [2.0, 0.5, -3.0, 1.0, 0.8];
+
+    let tensors: Vec<Tensor> = Tensor::from_vec(values.to_vec());
+    let [x1, x2, w1, w2, b] = &tensors[..] else {
+        unreachable!()
+    };
+    let n = &(&(x1 * w1) + &(x2 * w2)) + b;
+    let o = &(&n.tanh() / &w2.exp()) - &(x1 - 1.0).pow(w1);
+    o.backward();
+
+    // 입력 하나씩 tangent = 1로 두고 forward-mode로 편미분
+    for (i, t) in tensors.iter().enumerate() {
+        let d: Vec<Dual> = values
+            .iter()
+            .enumerate()
+            .map(|(j, v)| Dual::new(*v, if i == j { 1.0 } else { 0.0 }))
+            .collect();
+        let [x1, x2, w1, w2, b] = d[..] else {
+            unreachable!()
+        };
+        let n = x1 * w1 + x2 * w2 + b;
+        let out = n.tanh() / w2.exp() - (x1 - 1.0).pow_dual(w1);
+
+        assert!((out.value - o.data()).abs() < 1e-12);
+        assert!(
+            (out.tangent - t.grad()).abs() < 1e-12,
+            "input {}: forward {} != reverse {}",
+            i,
+            out.tangent,
+            t.grad()
+        );
+    }
+}
+
+type DualFn = fn(Dual) -> Dual;
+type TensorFn = fn(&Tensor) -> Tensor;
+
+#[test]
+fn test_unary_ops() {
+    let fs: Vec<(DualFn, TensorFn)> = vec![
+        (|x| -x, |x| -x),
+        (|x| x.exp(), |x| x.exp()),
+        (|x| x.tanh(), |x| x.tanh()),
+        (|x| x.relu(), |x| x.relu()),
+        (|x| x.sigmoid(), |x| x.sigmoid()),
+        (|x| x.log(), |x| x.log()),
+        (|x| x.sqrt(), |x| x.sqrt()),
+        (|x| x.sin(), |x| x.sin()),
+        (|x| x.cos(), |x| x.cos()),
+        (|x| 1.0 / x, |x| x.pow(-1.0)),
+    ];
+
+    for (fd, ft) in fs {
+        for v in [0.3, 1.7] {
+            let d = fd(Dual::variable(v));
+            let t = Tensor::new(v);
+            let out = ft(&t);
+            out.backward();
+            assert!((d.value - out.data()).abs() < 1e-12);
+            assert!((d.tangent - t.grad()).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_jvp() {
+    // f(x, y) = (x * y, x + sin(y))
+    let (values, jv) = jvp(
+        |xs| vec![xs[0] * xs[1], xs[0] + xs[1].sin()],
+        &[2.0, 0.5],
+        &[1.0, -1.0],
+    );
+    assert_eq!(values, vec![1.0, 2.0 + 0.5_f64.sin()]);
+    // J = [[y, x], [1, cos(y)]]
+    assert_eq!(jv, vec![0.5 - 2.0, 1.0 - 0.5_f64.cos()]);
+}
@@ -0,0 +1,59 @@
+use rust_micrograd::engine::{Tensor, gradcheck};
+
+#[test]
+fn test_gradcheck() {
+    let inputs = vec![
+        Tensor::new_with_label(2.0, "x1"),
+        Tensor::new_with_label(0.0, "x2"),
+        Tensor::new_with_label(-3.0, "w1"),
+        Tensor::new_with_label(1.0, "w2"),
+        Tensor::new_with_label(6.88137358, "b"),
+    ];
+
+    // tanh(x1*w1 + x2*w2 + b)
+    let report = gradcheck(
+        |xs| (&(&(&xs[0] * &xs[2]) + &(&xs[1] * &xs[3])) + &xs[4]).tanh(),
+        &inputs,
+        1e-6,
+        1e-6,
+    );
+    println!("{}", report);
+    assert!(report.passed());
+    assert_eq!(report.entries.len(), 5);
+    assert_eq!(report.entries[2].label, "w1");
+
+    // 입력 상태는 그대로
+    assert_eq!(inputs[0].data(), 2.0);
+    assert!(inputs.iter().all(|x| x.grad() == 0.0));
+}
+
+#[test]
+fn test_gradcheck_keeps_captured_grads() {
+    // f가 잡아 둔 파라미터 w의 기울기도 건드리지 않음
+    let w = Tensor::new_with_label(-3.0, "w");
+    w.set_grad(0.25);
+    let inputs = vec![Tensor::new_with_label(2.0, "x")];
+    inputs[0].set_grad(0.5);
+
+    let report = gradcheck(|xs| (&xs[0] * &w).tanh(), &inputs, 1e-6, 1e-6);
+    assert!(report.passed());
+    assert_eq!(w.grad(), 0.25);
+    assert_eq!(inputs[0].grad(), 0.5);
+}
+
+#[test]
+fn test_gradcheck_reports_mismatch() {
+    // relu는 0에서 꺾이므로 중앙 차분(0.5)과 subgradient(0)가 다름
+    let inputs = vec![
+        Tensor::new_with_label(0.0, "x"),
+        Tensor::new_with_label(1.5, "y"),
+    ];
+    let report = gradcheck(|xs| &xs[0].relu() * &xs[1], &inputs, 1e-6, 1e-6);
+    println!("{}", report);
+
+    assert!(!report.passed());
+    let mismatches = report.mismatches();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].label, "x");
+    assert!((mismatches[0].numeric - 0.75).abs() < 1e-6);
+}
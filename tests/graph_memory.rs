@@ -0,0 +1,138 @@
+use rust_micrograd::{
+    engine::{GraphStats, Tensor, live_tensors, no_grad},
+    nn::MLP,
+};
+
+// 테스트는 thread마다 따로 돌기 때문에 live_tensors는 다른 테스트의 영향을 받지 않음
+
+fn neuron() -> (Vec<Tensor>, Tensor) {
+    let x1 = Tensor::new_with_label(2.0, "x1");
+    let w1 = Tensor::new_with_label(-3.0, "w1");
+    let b = Tensor::new_with_label(6.881373587019543, "b");
+    let n = &(&x1 * &w1) + &b;
+    let o = n.tanh();
+    (vec![x1, w1, b], o)
+}
+
+#[test]
+fn test_graph_stats() {
+    let (_, o) = neuron();
+    assert_eq!(o.graph_size(), 6);
+
+    let stats = o.graph_stats();
+    println!("{:?}", stats);
+    assert_eq!(
+        stats,
+        GraphStats {
+            nodes: 6,
+            leaves: 3,
+            edges: 5,
+            bytes: stats.bytes,
+        }
+    );
+    assert!(stats.bytes > 0);
+}
+
+#[test]
+fn test_retain_graph() {
+    let (leaves, o) = neuron();
+    o.backward_retain(true);
+    assert_eq!(o.graph_size(), 6);
+
+    // 그래프 전체의 기울기를 0으로 만든 뒤 다시 backward 하면 같은 결과
+    let grads: Vec<f64> = leaves.iter().map(|t| t.grad()).collect();
+    o.topological_sort().iter().for_each(|t| t.set_grad(0.0));
+    o.backward();
+    assert_eq!(grads, leaves.iter().map(|t| t.grad()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_backward_accumulates() {
+    // z = 3 * x^2 → dz/dx = 6x
+    let x = Tensor::new(2.0);
+    let y = &x * &x;
+    let z = &y * 3.0;
+
+    z.backward();
+    assert_eq!(y.grad(), 3.0);
+    assert_eq!(x.grad(), 12.0);
+
+    // 기울기를 지우지 않고 다시 backward 하면 중간 노드의 기울기도 누적됨
+    // (root는 다시 1에서 시작)
+    z.backward();
+    assert_eq!(z.grad(), 1.0);
+    assert_eq!(y.grad(), 6.0);
+    assert_eq!(x.grad(), 12.0 + 24.0);
+}
+
+#[test]
+fn test_release_graph() {
+    let before = live_tensors();
+    let (leaves, o) = neuron();
+    assert_eq!(live_tensors(), before + 6);
+
+    o.backward_retain(false);
+    // 중간 노드는 root가 살아 있어도 해제됨
+    assert_eq!(live_tensors(), before + 4);
+    assert!(o.is_leaf());
+    assert_eq!(o.graph_size(), 1);
+    assert_eq!(o.grad(), 1.0);
+
+    // leaf 기울기는 그대로 남음
+    assert!((leaves[0].grad() - -1.5).abs() < 1e-6);
+    assert!((leaves[1].grad() - 1.0).abs() < 1e-6);
+    assert!((leaves[2].grad() - 0.5).abs() < 1e-6);
+
+    drop(o);
+    drop(leaves);
+    assert_eq!(live_tensors(), before);
+}
+
+#[test]
+fn test_shared_leaf_release() {
+    // 여러 번 쓰인 노드도 모든 소비자가 처리된 뒤에 끊김
+    let x = Tensor::new(3.0);
+    let h = &x * &x;
+    let y = &(&h * &h) + &h;
+    y.backward_retain(false);
+    assert_eq!(x.grad(), 4.0 * 27.0 + 6.0);
+    assert!(h.is_leaf());
+}
+
+#[test]
+fn test_training_loop_does_not_leak() {
+    let mlp: MLP = MLP::new(3, vec![4, 4, 1]);
+    let xs = [[2.0, 3.0, -1.0], [3.0, -1.0, 0.5], [0.5, 1.0, 1.0]];
+    let ys = [1.0, -1.0, -1.0];
+
+    let mut counts = Vec::new();
+    let mut keep = None;
+    for _ in 0..5 {
+        let loss: Tensor = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| {
+                let pred = mlp.forward(&Tensor::from_vec(x.to_vec())).remove(0);
+                (&pred - y).pow(2.0)
+            })
+            .sum();
+
+        for p in mlp.parameters().concat() {
+            p.set_grad(0.0);
+        }
+        loss.backward_retain(false);
+        no_grad(|| {
+            for p in mlp.parameters().concat() {
+                p.set_data(p.data() - 0.05 * p.grad());
+            }
+        });
+
+        // loss를 계속 들고 있어도 그래프는 남지 않음
+        keep = Some(loss);
+        counts.push(live_tensors());
+    }
+
+    println!("{:?}", counts);
+    assert!(counts.windows(2).all(|w| w[0] == w[1]));
+    assert_eq!(keep.unwrap().graph_size(), 1);
+}
@@ -0,0 +1,126 @@
+use rust_micrograd::engine::{Tensor, live_tensors};
+
+#[test]
+fn test_second_derivative() {
+    // f(x) = x^3, f'(x) = 3x^2, f''(x) = 6x
+    let x = Tensor::new_with_label(2.0, "x");
+    let f = x.pow(3.0);
+    let dx = f.backward_create_graph(std::slice::from_ref(&x)).remove(0);
+    assert_eq!(x.grad(), 12.0);
+
+    println!("dx: {:?}", dx);
+    assert_eq!(dx.data(), 12.0);
+
+    x.set_grad(0.0);
+    dx.backward();
+    assert_eq!(x.grad(), 12.0);
+}
+
+#[test]
+fn test_activations_second_derivative() {
+    let h = 1e-4;
+    let fs: Vec<fn(&Tensor) -> Tensor> = vec![
+        |x| x.tanh(),
+        |x| x.sigmoid(),
+        |x| x.exp(),
+        |x| x.log(),
+        |x| x.sqrt(),
+        |x| x.sin(),
+        |x| x.cos(),
+        |x| x.softplus(),
+        |x| x.gelu(),
+        |x| x.silu(),
+        |x| x.elu(1.0),
+        |x| x.pow(x),
+        |x| &(x * x) / &x.exp(),
+    ];
+
+    // f'의 수치 미분과 f''를 비교
+    let first = |f: fn(&Tensor) -> Tensor, v: f64| {
+        let x = Tensor::new(v);
+        f(&x).backward();
+        x.grad()
+    };
+    for f in fs {
+        for v in [-0.8, 0.4, 1.3] {
+            let v = if v < 0.0 && f(&Tensor::new(v)).data().is_nan() {
+                -v
+            } else {
+                v
+            };
+            let x = Tensor::new(v);
+            let dx = f(&x)
+                .backward_create_graph(std::slice::from_ref(&x))
+                .remove(0);
+            assert!((dx.data() - first(f, v)).abs() < 1e-9);
+
+            x.set_grad(0.0);
+            dx.backward();
+            let numeric = (first(f, v + h) - first(f, v - h)) / (2.0 * h);
+            assert!(
+                (x.grad() - numeric).abs() < 1e-5,
+                "x = {}: {} != {}",
+                v,
+                x.grad(),
+                numeric
+            );
+        }
+    }
+}
+
+#[test]
+fn test_hessian_vector_product() {
+    // f(x, y) = x^2 * y + sin(y)
+    // H = [[2y, 2x], [2x, -sin(y)]]
+    let x = Tensor::new_with_label(1.5, "x");
+    let y = Tensor::new_with_label(-0.5, "y");
+    let f = &(&x.pow(2.0) * &y) + &y.sin();
+    let grads = f.backward_create_graph(&[x.clone(), y.clone()]);
+
+    let (gx, gy) = (grads[0].clone(), grads[1].clone());
+    let v = (0.3, -2.0);
+    let gv = &(&gx * v.0) + &(&gy * v.1);
+
+    x.set_grad(0.0);
+    y.set_grad(0.0);
+    gv.backward();
+
+    let hv = (
+        2.0 * y.data() * v.0 + 2.0 * x.data() * v.1,
+        2.0 * x.data() * v.0 - y.data().sin() * v.1,
+    );
+    assert!((x.grad() - hv.0).abs() < 1e-12);
+    assert!((y.grad() - hv.1).abs() < 1e-12);
+}
+
+#[test]
+fn test_gradient_penalty() {
+    // loss = (d/dw (w * x)^2)^2 = (2 w x^2)^2, d loss / dw = 8 w x^4
+    let w = Tensor::new_with_label(0.7, "w");
+    let x = Tensor::new_with_label(1.2, "x");
+    let dw = (&w * &x)
+        .pow(2.0)
+        .backward_create_graph(std::slice::from_ref(&w));
+
+    let penalty = dw[0].pow(2.0);
+    w.set_grad(0.0);
+    x.set_grad(0.0);
+    penalty.backward();
+
+    assert!((w.grad() - 8.0 * 0.7 * 1.2_f64.powi(4)).abs() < 1e-12);
+}
+
+#[test]
+fn test_create_graph_releases_graph() {
+    let before = live_tensors();
+    {
+        let x = Tensor::new(0.3);
+        let f = x.exp().tanh();
+        let dx = f.backward_create_graph(std::slice::from_ref(&x)).remove(0);
+        x.set_grad(0.0);
+        dx.backward();
+        assert!(live_tensors() > before);
+    }
+    // 기울기 그래프를 노드에 저장하지 않으므로 순환 참조 없이 모두 해제됨
+    assert_eq!(live_tensors(), before);
+}
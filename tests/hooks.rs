@@ -0,0 +1,138 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rust_micrograd::{
+    engine::Tensor,
+    nn::{MLP, Neuron},
+};
+
+#[test]
+fn test_grad_hook_observe() {
+    let x = Tensor::new_with_label(2.0, "x");
+    let w = Tensor::new_with_label(-3.0, "w");
+    let n = &x * &w;
+    let o = n.tanh();
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    n.register_hook(move |grad| {
+        log.borrow_mut().push(grad);
+        grad
+    });
+
+    o.backward();
+    println!("{:?}", seen.borrow());
+    assert_eq!(seen.borrow().len(), 1);
+    assert_eq!(seen.borrow()[0], n.grad());
+    assert_eq!(x.grad(), n.grad() * -3.0);
+}
+
+#[test]
+fn test_grad_hook_modify() {
+    // hook이 바꾼 기울기가 _prev로 전달됨
+    let x = Tensor::new(2.0);
+    let y = &x * 3.0;
+    let z = &y * &y;
+    y.register_hook(|grad| grad.clamp(-1.0, 1.0));
+
+    z.backward();
+    assert_eq!(y.grad(), 1.0);
+    assert_eq!(x.grad(), 3.0);
+
+    // 여러 hook은 등록 순서대로
+    y.register_hook(|grad| grad * 10.0);
+    z.topological_sort().iter().for_each(|t| t.set_grad(0.0));
+    z.backward();
+    assert_eq!(y.grad(), 10.0);
+    assert_eq!(x.grad(), 30.0);
+
+    y.clear_hooks();
+    z.topological_sort().iter().for_each(|t| t.set_grad(0.0));
+    z.backward();
+    assert_eq!(y.grad(), 12.0);
+    assert_eq!(x.grad(), 36.0);
+}
+
+#[test]
+fn test_grad_hook_on_leaf() {
+    // leaf hook은 이번 backward에서 들어온 기울기를 받고, 결과는 기존 값에 누적됨
+    let x = Tensor::new(1.5);
+    let y = &x * &x;
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = seen.clone();
+    x.register_hook(move |grad| {
+        log.borrow_mut().push(grad);
+        grad * 0.5
+    });
+
+    y.backward();
+    y.backward();
+    assert_eq!(*seen.borrow(), vec![3.0, 3.0]);
+    assert_eq!(x.grad(), 3.0);
+}
+
+#[test]
+fn test_grad_hook_root() {
+    let x = Tensor::new(2.0);
+    let y = &x * 4.0;
+    y.register_hook(|grad| grad * 0.5);
+    y.backward();
+    assert_eq!(y.grad(), 0.5);
+    assert_eq!(x.grad(), 2.0);
+}
+
+#[test]
+fn test_forward_hooks() {
+    let neuron = Neuron::new(2);
+    let captured = Rc::new(RefCell::new(Vec::new()));
+    let log = captured.clone();
+    neuron.register_forward_hook(move |x, out| {
+        assert_eq!(x.len(), 2);
+        log.borrow_mut().push(out.data());
+    });
+
+    let out = neuron.forward(&Tensor::from_vec(vec![0.5, -1.0]));
+    assert_eq!(*captured.borrow(), vec![out.data()]);
+
+    neuron.clear_forward_hooks();
+    neuron.forward(&Tensor::from_vec(vec![0.5, -1.0]));
+    assert_eq!(captured.borrow().len(), 1);
+}
+
+#[test]
+fn test_mlp_activations() {
+    let mlp: MLP = MLP::new(3, vec![4, 4, 1]);
+    let activations = Rc::new(RefCell::new(Vec::new()));
+
+    for (i, layer) in mlp.layers().iter().enumerate() {
+        let log = activations.clone();
+        layer.register_forward_hook(move |_, out| {
+            let mean_abs = out.iter().map(|o| o.data().abs()).sum::<f64>() / out.len() as f64;
+            log.borrow_mut().push((i, mean_abs));
+        });
+    }
+    let outputs = Rc::new(RefCell::new(0));
+    let count = outputs.clone();
+    mlp.register_forward_hook(move |x, out| {
+        assert_eq!(x.len(), 3);
+        *count.borrow_mut() += out.len();
+    });
+
+    let out = mlp.forward(&Tensor::from_vec(vec![2.0, 3.0, -1.0]));
+    println!("{:?}", activations.borrow());
+    let layers: Vec<usize> = activations.borrow().iter().map(|(i, _)| *i).collect();
+    assert_eq!(layers, vec![0, 1, 2]);
+    assert!(activations.borrow().iter().all(|(_, a)| *a <= 1.0));
+    assert_eq!(*outputs.borrow(), out.len());
+
+    // 기울기 hook과 함께 사용하면 층마다 기울기 크기를 기록할 수 있음
+    let grads = Rc::new(RefCell::new(Vec::new()));
+    for p in mlp.layers()[0].parameters() {
+        let log = grads.clone();
+        p.register_hook(move |grad| {
+            log.borrow_mut().push(grad);
+            grad
+        });
+    }
+    out[0].backward();
+    assert_eq!(grads.borrow().len(), mlp.layers()[0].parameters().len());
+}
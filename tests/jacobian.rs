@@ -0,0 +1,163 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use rust_micrograd::{
+    engine::{CustomOp, Tensor, hessian, jacobian, live_tensors, logsumexp, softmax},
+    nn::MLP,
+};
+
+fn assert_close(a: &[Vec<f64>], b: &[Vec<f64>], tol: f64) {
+    assert_eq!(a.len(), b.len());
+    for (ra, rb) in a.iter().zip(b) {
+        assert_eq!(ra.len(), rb.len());
+        for (x, y) in ra.iter().zip(rb) {
+            assert!((x - y).abs() < tol, "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_linear() {
+    let w = [[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]];
+    let x = Tensor::from_vec(vec![0.3, -0.7, 1.1]);
+    let outputs: Vec<Tensor> = w
+        .iter()
+        .map(|row| Tensor::dot(&Tensor::from_vec(row.to_vec()), &x))
+        .collect();
+    let j = jacobian(&outputs, &x);
+    println!("{:?}", j);
+    assert_eq!(j, vec![w[0].to_vec(), w[1].to_vec()]);
+}
+
+#[test]
+fn test_mlp() {
+    let mlp: MLP = MLP::new(3, vec![4, 2]);
+    let point = [0.5, -1.0, 2.0];
+    let x = Tensor::from_vec(point.to_vec());
+    let outputs = mlp.forward(&x);
+
+    // 파라미터 기울기가 이미 있어도 섞이지 않고, 끝난 뒤 그대로 남음
+    let params = mlp.parameters().concat();
+    params.iter().for_each(|p| p.set_grad(0.25));
+    let j = jacobian(&outputs, &x);
+    assert!(params.iter().all(|p| p.grad() == 0.25));
+    assert!(x.iter().all(|t| t.grad() == 0.0));
+
+    // 중앙 차분과 비교
+    let h = 1e-6;
+    let forward = |v: &[f64]| -> Vec<f64> {
+        let out = mlp.forward(&Tensor::from_vec(v.to_vec()));
+        out.iter().map(|t| t.data()).collect()
+    };
+    let mut numeric = vec![vec![0.0; 3]; 2];
+    for k in 0..3 {
+        let (mut plus, mut minus) = (point, point);
+        plus[k] += h;
+        minus[k] -= h;
+        let (fp, fm) = (forward(&plus), forward(&minus));
+        for i in 0..2 {
+            numeric[i][k] = (fp[i] - fm[i]) / (2.0 * h);
+        }
+    }
+    assert_close(&j, &numeric, 1e-6);
+
+    // 파라미터에 대한 jacobian, 그래프는 그대로 재사용 가능
+    let size = outputs[0].graph_size();
+    let jp = jacobian(&outputs, &params);
+    assert_eq!(jp.len(), 2);
+    assert_eq!(jp[0].len(), params.len());
+    assert_eq!(outputs[0].graph_size(), size);
+    assert_eq!(jacobian(&outputs, &x), j);
+}
+
+#[test]
+fn test_hessian() {
+    // f = x^2 y + sin(x) y^3
+    let x = Tensor::new_with_label(0.7, "x");
+    let y = Tensor::new_with_label(-1.2, "y");
+    let f = &(&x.pow(2.0) * &y) + &(&x.sin() * &y.pow(3.0));
+    let inputs = [x.clone(), y.clone()];
+    let h = hessian(&f, &inputs);
+    println!("{:?}", h);
+
+    let (a, b) = (0.7_f64, -1.2_f64);
+    let expected = vec![
+        vec![
+            2.0 * b - a.sin() * b.powi(3),
+            2.0 * a + 3.0 * a.cos() * b * b,
+        ],
+        vec![2.0 * a + 3.0 * a.cos() * b * b, 6.0 * a.sin() * b],
+    ];
+    assert_close(&h, &expected, 1e-12);
+    assert_eq!(x.grad(), 0.0);
+}
+
+#[test]
+fn test_hessian_logsumexp() {
+    let xs = Tensor::from_vec(vec![0.2, -0.4, 1.1]);
+    let s: Vec<f64> = softmax(&xs).iter().map(|t| t.data()).collect();
+    let h = hessian(&logsumexp(&xs), &xs);
+    let expected: Vec<Vec<f64>> = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| if i == j { s[i] } else { 0.0 } - s[i] * s[j])
+                .collect()
+        })
+        .collect();
+    assert_close(&h, &expected, 1e-12);
+}
+
+#[test]
+fn test_unused_input() {
+    let x = Tensor::new(2.0);
+    let z = Tensor::new(5.0);
+    let f = x.pow(3.0);
+    let inputs = [x.clone(), z.clone()];
+    assert_eq!(
+        jacobian(std::slice::from_ref(&f), &inputs),
+        vec![vec![12.0, 0.0]]
+    );
+    assert_eq!(hessian(&f, &inputs), vec![vec![12.0, 0.0], vec![0.0, 0.0]]);
+}
+
+#[test]
+fn test_no_leak() {
+    let before = live_tensors();
+    {
+        let xs = Tensor::from_vec(vec![0.5, -0.3]);
+        let f = (&(&xs[0] * &xs[1]) + &xs[0].exp()).tanh();
+        for _ in 0..3 {
+            hessian(&f, &xs);
+        }
+    }
+    assert_eq!(live_tensors(), before);
+}
+
+// x^3
+struct Cube;
+
+impl CustomOp for Cube {
+    fn name(&self) -> &str {
+        "cube"
+    }
+
+    fn forward(&self, inputs: &[f64]) -> f64 {
+        inputs[0].powi(3)
+    }
+
+    fn backward(&self, inputs: &[f64], _output: f64, out_grad: f64) -> Vec<f64> {
+        vec![3.0 * inputs[0].powi(2) * out_grad]
+    }
+}
+
+#[test]
+fn test_hessian_custom_op() {
+    let x = Tensor::new(2.0);
+    x.set_grad(1.5);
+    let f = &Tensor::apply(Cube, std::slice::from_ref(&x)) * &x;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| hessian(&f, std::slice::from_ref(&x))));
+    let message = result.unwrap_err().downcast::<String>().unwrap();
+    assert_eq!(*message, "hessian does not support custom op cube");
+    // 거부하기 전에는 기울기를 바꾸지 않음
+    assert_eq!(x.grad(), 1.5);
+    assert_eq!(f.grad(), 0.0);
+}
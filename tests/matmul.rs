@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+
+use rust_micrograd::{
+    engine::{NdTensor, Program, Tensor, gradcheck, hessian},
+    nn::{Layer, Neuron},
+};
+
+fn matrix(rows: usize, cols: usize, seed: f64) -> NdTensor {
+    let data = (0..rows * cols)
+        .map(|i| ((i as f64 + seed) * 0.37).sin())
+        .collect();
+    NdTensor::new(data, &[rows, cols])
+}
+
+// 블록 없이 정의대로 계산
+fn naive(a: &NdTensor, b: &NdTensor) -> Vec<f64> {
+    let (m, k, n) = (a.shape()[0], a.shape()[1], b.shape()[1]);
+    let mut out = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            out[i * n + j] = (0..k).map(|p| a.get(&[i, p]) * b.get(&[p, j])).sum();
+        }
+    }
+    out
+}
+
+#[test]
+fn test_matmul() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]);
+    let b = NdTensor::new(vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0], &[3, 2]);
+    let c = a.matmul(&b);
+    assert_eq!(c.shape(), vec![2, 2]);
+    assert_eq!(c.data(), vec![58.0, 64.0, 139.0, 154.0]);
+
+    // 노드 하나로 기록
+    assert_eq!(c.topological_sort().len(), 3);
+
+    // 블록 크기보다 큰 행렬
+    let a = matrix(70, 130, 0.0);
+    let b = matrix(130, 90, 1.0);
+    let c = a.matmul(&b);
+    for (x, y) in c.data().iter().zip(naive(&a, &b)) {
+        assert!((x - y).abs() < 1e-9);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot matmul")]
+fn test_matmul_mismatch() {
+    let _ = matrix(2, 3, 0.0).matmul(&matrix(2, 3, 0.0));
+}
+
+#[test]
+fn test_matmul_grad() {
+    let a = matrix(4, 3, 0.0);
+    let b = matrix(3, 5, 2.0);
+    let w = matrix(4, 5, 3.0);
+
+    // L = sum(tanh(A B) * W)
+    let loss = (&a.matmul(&b).tanh() * &w).sum_all();
+    loss.backward();
+
+    // dL/dC 를 직접 구해서 dA = dC B^T, dB = A^T dC 와 비교
+    let c = a.matmul(&b);
+    let dc: Vec<f64> = c
+        .data()
+        .iter()
+        .zip(w.data())
+        .map(|(c, w)| (1.0 - c.tanh().powi(2)) * w)
+        .collect();
+    let dc = NdTensor::new(dc, &[4, 5]);
+    let da = dc.matmul(&b.t());
+    let db = a.t().matmul(&dc);
+    for (x, y) in a.grad().iter().zip(da.data()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+    for (x, y) in b.grad().iter().zip(db.data()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+
+    // 중앙 차분
+    let h = 1e-6;
+    let f = || (&a.matmul(&b).tanh() * &w).sum_all().item();
+    let data = a.data();
+    for i in 0..data.len() {
+        let mut shifted = data.clone();
+        shifted[i] += h;
+        a.set_data(shifted.clone());
+        let hi = f();
+        shifted[i] -= 2.0 * h;
+        a.set_data(shifted);
+        let lo = f();
+        a.set_data(data.clone());
+        assert!((a.grad()[i] - (hi - lo) / (2.0 * h)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_matmul_same_operand() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]);
+    a.matmul(&a).sum_all().backward();
+    // d/dA sum(A A) = 1 B^T + A^T 1
+    assert_eq!(a.grad(), vec![7.0, 11.0, 9.0, 13.0]);
+}
+
+#[test]
+fn test_nd_dot() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0], &[3]);
+    let b = NdTensor::new(vec![4.0, -5.0, 6.0], &[3]);
+    let c = a.dot(&b);
+    assert_eq!(c.shape(), Vec::<usize>::new());
+    assert_eq!(c.item(), 12.0);
+
+    c.backward();
+    assert_eq!(a.grad(), b.data());
+    assert_eq!(b.grad(), a.data());
+}
+
+#[test]
+fn test_dot() {
+    let xs = Tensor::from_vec(vec![1.0, -2.0, 0.5]);
+    let ws = Tensor::from_vec(vec![0.3, 0.8, -1.5]);
+    let d = Tensor::dot(&ws, &xs);
+    assert_eq!(d.data(), 0.3 - 1.6 - 0.75);
+    assert_eq!(d.prev().len(), 6);
+
+    let report = gradcheck(
+        |t| Tensor::dot(&t[..3], &t[3..]).tanh(),
+        &[ws, xs].concat(),
+        1e-6,
+        1e-5,
+    );
+    println!("{}", report);
+    assert!(report.passed());
+
+    // 같은 Tensor를 양쪽에 넣으면 기울기 2x
+    let x = [Tensor::new(3.0)];
+    let y = Tensor::dot(&x, &x);
+    y.backward();
+    assert_eq!(x[0].grad(), 6.0);
+
+    // 2차 미분
+    let x = Tensor::new(3.0);
+    let w = Tensor::new(2.0);
+    let y = Tensor::dot(&[x.clone(), x.clone()], &[x.clone(), w.clone()]);
+    let dx = y.backward_create_graph(std::slice::from_ref(&x)).remove(0);
+    assert_eq!(dx.data(), 2.0 * 3.0 + 2.0);
+    x.set_grad(0.0);
+    w.set_grad(0.0);
+    dx.backward();
+    assert_eq!(x.grad(), 2.0);
+    assert_eq!(w.grad(), 1.0);
+}
+
+#[test]
+fn test_neuron_uses_dot() {
+    let n = Neuron::new(3);
+    let x = Tensor::from_vec(vec![1.0, -2.0, 0.5]);
+    let out = n.forward(&x);
+
+    // tanh <- (+) <- dot
+    let nodes = out.topological_sort();
+    assert_eq!(nodes.len(), 3 + 3 + 1 + 3);
+
+    let expected = (n
+        .weights()
+        .iter()
+        .zip(&x)
+        .map(|(w, x)| w.data() * x.data())
+        .sum::<f64>()
+        + n.bias().data())
+    .tanh();
+    assert!((out.data() - expected).abs() < 1e-12);
+}
+
+// 3 x 2 행렬과 길이 2 벡터, 앞 6개가 w (행 우선), 뒤 2개가 x
+fn split(t: &[Tensor]) -> (Vec<Vec<Tensor>>, Vec<Tensor>) {
+    (
+        t[..6].chunks(2).map(|row| row.to_vec()).collect(),
+        t[6..].to_vec(),
+    )
+}
+
+fn matvec_loss(t: &[Tensor]) -> Tensor {
+    let (w, x) = split(t);
+    Tensor::matvec(&w, &x)
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (o * (i as f64 + 1.0)).tanh())
+        .sum()
+}
+
+fn labeled(values: &[f64]) -> Vec<Tensor> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| Tensor::new_with_label(*v, &format!("t{}", i)))
+        .collect()
+}
+
+#[test]
+fn test_matvec() {
+    let t = labeled(&[0.3, -0.8, 1.5, 0.2, -0.4, 0.9, 1.0, -2.0]);
+    let (w, x) = split(&t);
+    let out = Tensor::matvec(&w, &x);
+
+    // 출력은 모두 w, x를 가진 공유 노드 하나만 가리킴
+    let node = out[0].prev()[0].clone();
+    assert_eq!(node.prev(), t);
+    for (o, row) in out.iter().zip(&w) {
+        assert_eq!(o.prev(), vec![node.clone()]);
+        assert_eq!(o.data(), Tensor::dot(row, &x).data());
+    }
+    assert_eq!(out[2].graph_size(), 8 + 1 + 1);
+
+    // 한 번의 backward로 dw = g x^T, dx = w^T g
+    let g = [1.0, -2.0, 0.5];
+    out.iter()
+        .zip(g)
+        .map(|(o, g)| o * g)
+        .sum::<Tensor>()
+        .backward();
+    for (i, row) in w.iter().enumerate() {
+        for (j, w_ij) in row.iter().enumerate() {
+            assert_eq!(w_ij.grad(), g[i] * x[j].data());
+        }
+    }
+    for (j, x_j) in x.iter().enumerate() {
+        let expected: f64 = (0..3).map(|i| w[i][j].data() * g[i]).sum();
+        assert!((x_j.grad() - expected).abs() < 1e-15);
+    }
+
+    let report = gradcheck(matvec_loss, &t, 1e-6, 1e-5);
+    println!("{}", report);
+    assert!(report.passed());
+
+    // 같은 Tensor가 w와 x 양쪽에 있으면 기울기를 합침
+    let a = Tensor::new(3.0);
+    let y = Tensor::matvec(&[vec![a.clone()]], std::slice::from_ref(&a)).remove(0);
+    y.backward();
+    assert_eq!(a.grad(), 6.0);
+}
+
+#[test]
+fn test_matvec_graph_tools() {
+    let t = labeled(&[0.3, -0.8, 1.5, 0.2, -0.4, 0.9, 1.0, -2.0]);
+    let out = matvec_loss(&t);
+    out.backward();
+    let expected: Vec<f64> = t.iter().map(|t| t.grad()).collect();
+
+    // Program은 행마다 dot 명령으로 실행
+    let mut program = Program::trace(8, |t| vec![matvec_loss(t)]);
+    let point: Vec<f64> = t.iter().map(|t| t.data()).collect();
+    program.forward(&point);
+    program.backward();
+    assert!((program.output(0) - out.data()).abs() < 1e-12);
+    for (a, b) in program.input_grads().iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    // 기호 미분
+    let vars: HashMap<String, f64> = t.iter().map(|t| (t.label(), t.data())).collect();
+    assert!((out.to_expr().eval(&vars) - out.data()).abs() < 1e-12);
+    for (x, g) in t.iter().zip(&expected) {
+        assert!((out.symbolic_grad(x).eval(&vars) - g).abs() < 1e-12);
+    }
+
+    // 저장 후 다시 불러오기
+    let loaded: Tensor = Tensor::from_json(&out.to_json()).unwrap();
+    assert_eq!(loaded.to_json(), out.to_json());
+
+    // 2차 미분: sum((w x)^2) 를 x로 두 번 미분하면 2 w^T w
+    let (w, x) = split(&t);
+    let f: Tensor = Tensor::matvec(&w, &x).iter().map(|o| o * o).sum();
+    let h = hessian(&f, &x);
+    for (j, row) in h.iter().enumerate() {
+        for (k, h_jk) in row.iter().enumerate() {
+            let expected: f64 = (0..3).map(|i| 2.0 * w[i][j].data() * w[i][k].data()).sum();
+            assert!((h_jk - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_layer_uses_matvec() {
+    let x = Tensor::from_vec(vec![1.0, -2.0, 0.5]);
+    let layer = Layer::new(3, 4);
+    let out = layer.forward(&x);
+    assert_eq!(out.len(), 4);
+
+    // tanh <- (+ bias) <- row <- matvec (w 4x3, x 3)
+    let node = out[0].prev()[0].prev()[0].prev()[0].clone();
+    assert_eq!(out[0].graph_size(), 12 + 3 + 1 + 1 + 1 + 1 + 1);
+    for o in &out {
+        assert_eq!(o.prev()[0].prev()[0].prev(), vec![node.clone()]);
+    }
+
+    // 뉴런마다 dot으로 계산한 것과 같은 값, 같은 기울기
+    out.iter().cloned().sum::<Tensor>().backward();
+    let grads: Vec<f64> = layer.parameters().iter().map(|p| p.grad()).collect();
+    assert!(grads.iter().all(|g| *g != 0.0));
+
+    layer.parameters().iter().for_each(|p| p.set_grad(0.0));
+    let per_neuron: Vec<Tensor> = layer.neurons().iter().map(|n| n.forward(&x)).collect();
+    for (a, b) in out.iter().zip(&per_neuron) {
+        assert!((a.data() - b.data()).abs() < 1e-15);
+    }
+    per_neuron.iter().cloned().sum::<Tensor>().backward();
+    for (p, g) in layer.parameters().iter().zip(&grads) {
+        assert!((p.grad() - g).abs() < 1e-15);
+    }
+}
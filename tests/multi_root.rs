@@ -0,0 +1,82 @@
+use rust_micrograd::engine::{Tensor, backward_many};
+
+#[test]
+fn test_backward_with() {
+    let x = Tensor::new(2.0);
+    let y = &x * &x;
+    y.backward_with(0.5);
+    assert_eq!(y.grad(), 0.5);
+    assert_eq!(x.grad(), 2.0);
+
+    // backward()는 seed 1과 같음
+    x.set_grad(0.0);
+    y.backward();
+    assert_eq!(x.grad(), 4.0);
+}
+
+#[test]
+fn test_backward_many() {
+    let x = Tensor::new_with_label(0.5, "x");
+    let w = Tensor::new_with_label(-1.5, "w");
+    let h = (&x * &w).tanh();
+    let task_a = &h * 3.0;
+    let task_b = h.exp();
+
+    backward_many(&[(task_a.clone(), 1.0), (task_b.clone(), 0.25)]);
+    let (gx, gw) = (x.grad(), w.grad());
+
+    // 합 노드를 만들어서 backward 한 것과 같음
+    let total = &(&task_a * 1.0) + &(&task_b * 0.25);
+    total
+        .topological_sort()
+        .iter()
+        .for_each(|t| t.set_grad(0.0));
+    total.backward();
+    assert!((gx - x.grad()).abs() < 1e-12);
+    assert!((gw - w.grad()).abs() < 1e-12);
+}
+
+#[test]
+fn test_backward_many_shared_nodes() {
+    // 한 root가 다른 root의 중간 노드인 경우 기울기가 더해짐
+    let x = Tensor::new(3.0);
+    let y = &x * 2.0;
+    let z = &y * &y;
+
+    backward_many(&[(y.clone(), 1.0), (z.clone(), 1.0)]);
+    assert_eq!(z.grad(), 1.0);
+    assert_eq!(y.grad(), 1.0 + 2.0 * 6.0);
+    assert_eq!(x.grad(), 2.0 * 13.0);
+
+    // 같은 그래프를 다시 돌려도 중간 노드는 새로 계산
+    x.set_grad(0.0);
+    backward_many(&[(y.clone(), 1.0), (z.clone(), 1.0)]);
+    assert_eq!(y.grad(), 13.0);
+    assert_eq!(x.grad(), 26.0);
+}
+
+#[test]
+fn test_vector_jacobian_product() {
+    // f(x1, x2) = (x1 * x2, x1 + x2, sin(x1)), v^T J
+    let x = Tensor::from_vec(vec![0.7, -1.2]);
+    let outputs = [&x[0] * &x[1], &x[0] + &x[1], x[0].sin()];
+    let v = [1.0, -2.0, 0.5];
+
+    let seeds: Vec<_> = outputs.iter().cloned().zip(v).collect();
+    backward_many(&seeds);
+
+    let j = [
+        [x[1].data(), x[0].data()],
+        [1.0, 1.0],
+        [x[0].data().cos(), 0.0],
+    ];
+    for (i, xi) in x.iter().enumerate() {
+        let expected: f64 = (0..3).map(|k| v[k] * j[k][i]).sum();
+        assert!((xi.grad() - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_backward_many_empty() {
+    backward_many::<f64>(&[]);
+}
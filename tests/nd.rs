@@ -0,0 +1,238 @@
+use rust_micrograd::engine::{NdTensor, no_grad};
+
+// 원소마다 중앙 차분을 구해 backward 결과와 비교 (f의 모든 출력 원소 합을 미분)
+fn check(f: impl Fn(&[NdTensor]) -> NdTensor, inputs: &[NdTensor]) {
+    for x in inputs {
+        x.zero_grad();
+    }
+    let out = f(inputs);
+    out.backward();
+    println!("{:?}", out);
+
+    let h = 1e-6;
+    for (n, x) in inputs.iter().enumerate() {
+        let data = x.data();
+        let grad = x.grad();
+        for i in 0..data.len() {
+            let mut shifted = data.clone();
+            shifted[i] = data[i] + h;
+            x.set_data(shifted.clone());
+            let hi: f64 = no_grad(|| f(inputs)).data().iter().sum();
+            shifted[i] = data[i] - h;
+            x.set_data(shifted);
+            let lo: f64 = no_grad(|| f(inputs)).data().iter().sum();
+            x.set_data(data.clone());
+
+            let numeric = (hi - lo) / (2.0 * h);
+            assert!(
+                (grad[i] - numeric).abs() < 1e-5,
+                "input {} element {}: analytic {} numeric {}",
+                n,
+                i,
+                grad[i],
+                numeric
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shape_and_strides() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]);
+    assert_eq!(a.shape(), vec![2, 3]);
+    assert_eq!(a.strides(), vec![3, 1]);
+    assert_eq!(a.ndim(), 2);
+    assert_eq!(a.len(), 6);
+    assert_eq!(a.get(&[1, 0]), 4.0);
+
+    let s = NdTensor::scalar(2.5);
+    assert_eq!(s.shape(), Vec::<usize>::new());
+    assert_eq!(s.item(), 2.5);
+}
+
+#[test]
+fn test_broadcasting() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]);
+    let row = NdTensor::new(vec![10.0, 20.0, 30.0], &[3]);
+    let col = NdTensor::new(vec![100.0, 200.0], &[2, 1]);
+
+    let b = &a + &row;
+    assert_eq!(b.shape(), vec![2, 3]);
+    assert_eq!(b.data(), vec![11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+
+    let c = &a * &col;
+    assert_eq!(c.data(), vec![100.0, 200.0, 300.0, 800.0, 1000.0, 1200.0]);
+
+    // [2, 1] 과 [3] -> [2, 3]
+    let d = &col - &row;
+    assert_eq!(d.shape(), vec![2, 3]);
+    assert_eq!(d.data(), vec![90.0, 80.0, 70.0, 190.0, 180.0, 170.0]);
+
+    let e = &a * 2.0;
+    assert_eq!(e.data(), vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+}
+
+#[test]
+#[should_panic(expected = "cannot be broadcast")]
+fn test_broadcasting_mismatch() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0], &[3]);
+    let b = NdTensor::new(vec![1.0, 2.0], &[2]);
+    let _ = &a + &b;
+}
+
+#[test]
+fn test_broadcasting_grad() {
+    let a = NdTensor::new_with_label(vec![0.5, -1.0, 2.0, 1.5, 0.3, -0.7], &[2, 3], "a");
+    let row = NdTensor::new_with_label(vec![1.2, -0.4, 0.9], &[3], "row");
+    let col = NdTensor::new_with_label(vec![0.8, 1.7], &[2, 1], "col");
+
+    check(
+        |t| &(&t[0] + &t[1]) * &t[2],
+        &[a.clone(), row.clone(), col.clone()],
+    );
+    check(
+        |t| &(&t[0] - &t[2]) / &t[1],
+        &[a.clone(), row.clone(), col.clone()],
+    );
+
+    // broadcast 된 축의 기울기는 더해짐
+    row.zero_grad();
+    let y = &a + &row;
+    y.backward();
+    assert_eq!(row.grad(), vec![2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn test_elementwise_grad() {
+    let a = [NdTensor::new(vec![0.5, -1.0, 2.0, 1.5, 0.3, -0.7], &[3, 2])];
+    check(|t| t[0].tanh(), &a);
+    check(|t| t[0].exp(), &a);
+    check(|t| t[0].sigmoid(), &a);
+    check(|t| t[0].relu(), &a);
+    check(|t| -&t[0], &a);
+    check(|t| t[0].pow(3.0), &a);
+
+    let b = [NdTensor::new(vec![0.5, 1.0, 2.0, 1.5], &[4])];
+    check(|t| t[0].log(), &b);
+    check(|t| t[0].pow(0.5), &b);
+}
+
+#[test]
+fn test_reductions() {
+    let a = NdTensor::new(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], &[2, 3]);
+
+    let s0 = a.sum(0);
+    assert_eq!(s0.shape(), vec![3]);
+    assert_eq!(s0.data(), vec![5.0, 7.0, 9.0]);
+    assert_eq!(a.sum(1).data(), vec![9.0, 12.0]);
+    assert_eq!(a.mean(1).data(), vec![3.0, 4.0]);
+    assert_eq!(a.max(0).data(), vec![4.0, 5.0, 6.0]);
+    assert_eq!(a.max(1).data(), vec![5.0, 6.0]);
+
+    let total = a.sum_all();
+    assert_eq!(total.shape(), Vec::<usize>::new());
+    assert_eq!(total.item(), 21.0);
+    assert_eq!(a.mean_all().item(), 3.5);
+    assert_eq!(a.max_all().item(), 6.0);
+}
+
+#[test]
+fn test_reduction_grad() {
+    let a = [NdTensor::new(
+        vec![
+            0.5, -1.0, 2.0, 1.5, 0.3, -0.7, 1.1, 0.2, -0.4, 0.9, 1.3, -2.0,
+        ],
+        &[2, 3, 2],
+    )];
+    for axis in 0..3 {
+        check(|t| t[0].sum(axis).tanh(), &a);
+        check(|t| t[0].mean(axis).exp(), &a);
+        check(|t| t[0].max(axis).pow(2.0), &a);
+    }
+    check(|t| t[0].sum_all(), &a);
+    check(|t| t[0].mean_all(), &a);
+
+    // 최댓값이 같은 원소가 여럿이면 처음 원소로만 기울기가 감
+    let b = NdTensor::new(vec![3.0, 1.0, 3.0], &[3]);
+    b.max(0).backward();
+    assert_eq!(b.grad(), vec![1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_reshape_transpose() {
+    let a = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]);
+
+    let r = a.reshape(&[3, 2]);
+    assert_eq!(r.shape(), vec![3, 2]);
+    assert_eq!(r.data(), a.data());
+
+    let t = a.t();
+    assert_eq!(t.shape(), vec![3, 2]);
+    assert_eq!(t.strides(), vec![2, 1]);
+    assert_eq!(t.data(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    assert_eq!(t.get(&[2, 1]), a.get(&[1, 2]));
+
+    let b = NdTensor::new((0..24).map(|x| x as f64).collect(), &[2, 3, 4]);
+    let c = b.transpose(0, 2);
+    assert_eq!(c.shape(), vec![4, 3, 2]);
+    assert_eq!(c.get(&[3, 1, 0]), b.get(&[0, 1, 3]));
+}
+
+#[test]
+fn test_reshape_transpose_grad() {
+    let a = NdTensor::new(vec![0.5, -1.0, 2.0, 1.5, 0.3, -0.7], &[2, 3]);
+    let w = NdTensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[3, 2]);
+    check(|t| &t[0].t() * &t[1], &[a.clone(), w.clone()]);
+    check(
+        |t| (&t[0].reshape(&[3, 2]) * &t[1]).tanh(),
+        &[a.clone(), w.clone()],
+    );
+    check(
+        |t| &t[0].reshape(&[6, 1]) * &t[1].reshape(&[6]),
+        &[a.clone(), w.clone()],
+    );
+}
+
+#[test]
+fn test_shared_subgraph() {
+    // 같은 노드를 여러 번 사용하면 기울기가 누적됨
+    let a = NdTensor::new(vec![0.5, -1.0, 2.0], &[3]);
+    check(
+        |t| {
+            let h = t[0].tanh();
+            &(&h * &h) + &h.sum(0)
+        },
+        std::slice::from_ref(&a),
+    );
+
+    // 반복 호출해도 같은 결과
+    a.zero_grad();
+    let y = (&a * &a).sum_all();
+    y.backward();
+    let first = a.grad();
+    a.zero_grad();
+    y.backward();
+    assert_eq!(a.grad(), first);
+}
+
+#[test]
+fn test_no_grad() {
+    let a = NdTensor::new(vec![1.0, 2.0], &[2]);
+    let b = no_grad(|| &a * &a);
+    assert!(b.is_leaf());
+    assert_eq!(b.data(), vec![1.0, 4.0]);
+
+    // mean 내부 계산은 그래프에 남지 않음
+    let m = a.mean(0);
+    assert_eq!(m.prev().len(), 1);
+    assert_eq!(m.prev()[0], a);
+}
+
+#[test]
+fn test_f32() {
+    let a = NdTensor::<f32>::leaf(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]);
+    let y = (&a * &a).sum(1).sum_all();
+    y.backward();
+    assert_eq!(y.item(), 30.0);
+    assert_eq!(a.grad(), vec![2.0, 4.0, 6.0, 8.0]);
+}
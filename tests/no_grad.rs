@@ -0,0 +1,61 @@
+use rust_micrograd::{
+    engine::{Tensor, is_grad_enabled, no_grad},
+    nn::MLP,
+};
+
+#[test]
+fn test_no_grad() {
+    let a = Tensor::new_with_label(2.0, "a");
+    let b = Tensor::new_with_label(-3.0, "b");
+
+    let c = no_grad(|| (&a * &b).tanh() + 1.0);
+    println!("c: {:?}", c);
+    assert_eq!(c.data(), (-6.0_f64).tanh() + 1.0);
+    assert!(c.is_leaf());
+    assert_eq!(c.topological_sort().len(), 1);
+
+    c.backward();
+    assert_eq!(a.grad(), 0.0);
+
+    // 밖에서는 다시 기록
+    assert!(is_grad_enabled());
+    let d = &a * &b;
+    assert_eq!(d.prev().len(), 2);
+}
+
+#[test]
+fn test_no_grad_nested_and_panic() {
+    no_grad(|| {
+        no_grad(|| assert!(!is_grad_enabled()));
+        assert!(!is_grad_enabled());
+    });
+    assert!(is_grad_enabled());
+
+    let result = std::panic::catch_unwind(|| no_grad(|| panic!("boom")));
+    assert!(result.is_err());
+    assert!(is_grad_enabled());
+}
+
+#[test]
+fn test_no_grad_inference() {
+    let mlp = MLP::new(3, vec![4, 4, 1]);
+    let x = Tensor::from_vec(vec![2.0, 3.0, -1.0]);
+
+    let out = no_grad(|| mlp.forward(&x));
+    assert!(out[0].is_leaf());
+    assert_eq!(out[0].data(), mlp.forward(&x)[0].data());
+}
+
+#[test]
+fn test_detach() {
+    let a = Tensor::new_with_label(2.0, "a");
+    let b = (&a * &a).detach();
+    assert_eq!(b.data(), 4.0);
+    assert!(b.is_leaf());
+
+    // detach 이후의 연산은 a로 기울기를 보내지 않음
+    let c = &b * &a;
+    c.backward();
+    assert_eq!(a.grad(), 4.0);
+    assert_eq!(b.grad(), 2.0);
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use rust_micrograd::{
+    engine::{Program, Tensor, gradcheck, parse},
+    nn::MLP,
+};
+
+fn grads(inputs: &[Tensor]) -> Vec<f64> {
+    inputs.iter().map(|t| t.grad()).collect()
+}
+
+#[test]
+fn test_max_min() {
+    let a = Tensor::new_with_label(2.0, "a");
+    let b = Tensor::new_with_label(-1.0, "b");
+    let ab = [a.clone(), b.clone()];
+
+    let m = a.max(&b);
+    m.backward();
+    assert_eq!(m.data(), 2.0);
+    assert_eq!(grads(&ab), vec![1.0, 0.0]);
+
+    ab.iter().for_each(|t| t.set_grad(0.0));
+    let m = a.min(&b);
+    m.backward();
+    assert_eq!(m.data(), -1.0);
+    assert_eq!(grads(&ab), vec![0.0, 1.0]);
+
+    // 같은 값이면 반씩 나눔
+    b.set_data(2.0);
+    let fs: [fn(&Tensor, &Tensor) -> Tensor; 2] = [|x, y| x.max(y), |x, y| x.min(y)];
+    for f in fs {
+        ab.iter().for_each(|t| t.set_grad(0.0));
+        f(&a, &b).backward();
+        assert_eq!(grads(&ab), vec![0.5, 0.5]);
+    }
+
+    // 같은 노드끼리는 합쳐서 1
+    a.set_grad(0.0);
+    a.max(&a).backward();
+    assert_eq!(a.grad(), 1.0);
+
+    // 같은 값에서의 중앙 차분도 0.5
+    let report = gradcheck(|t| t[0].max(&t[1]), &ab, 1e-6, 1e-6);
+    println!("{}", report);
+    assert!(report.passed());
+}
+
+#[test]
+fn test_clamp() {
+    let x = Tensor::new(0.0);
+    for (value, expected, grad) in [
+        (-2.0, -1.0, 0.0),
+        (-1.0, -1.0, 0.5),
+        (0.3, 0.3, 1.0),
+        (1.0, 1.0, 0.5),
+        (5.0, 1.0, 0.0),
+    ] {
+        x.set_data(value);
+        x.set_grad(0.0);
+        let y = x.clamp(-1.0, 1.0);
+        y.backward();
+        assert_eq!(y.data(), expected);
+        // 경계에서는 max, min처럼 반만 전달
+        assert_eq!(x.grad(), grad, "{}", value);
+
+        // min(max(x, lo), hi)와 같은 기울기
+        let (lo, hi) = (Tensor::new(-1.0), Tensor::new(1.0));
+        let grad = x.grad();
+        x.set_grad(0.0);
+        x.max(&lo).min(&hi).backward();
+        assert_eq!(x.grad(), grad, "{}", value);
+    }
+}
+
+#[test]
+#[should_panic(expected = "clamp requires lo <= hi")]
+fn test_clamp_bounds() {
+    Tensor::new(0.0).clamp(1.0, -1.0);
+}
+
+#[test]
+fn test_where() {
+    let cond = Tensor::new_with_label(1.0, "c");
+    let a = Tensor::new_with_label(3.0, "a");
+    let b = Tensor::new_with_label(4.0, "b");
+    let inputs = [cond.clone(), a.clone(), b.clone()];
+
+    let y = &Tensor::where_(&cond, &a, &b) * &b;
+    y.backward();
+    assert_eq!(y.data(), 12.0);
+    assert_eq!(grads(&inputs), vec![0.0, 4.0, 3.0]);
+
+    inputs.iter().for_each(|t| t.set_grad(0.0));
+    cond.set_data(0.0);
+    let y = &Tensor::where_(&cond, &a, &b) * &b;
+    y.backward();
+    assert_eq!(y.data(), 16.0);
+    assert_eq!(grads(&inputs), vec![0.0, 0.0, 8.0]);
+}
+
+#[test]
+fn test_second_order() {
+    // max(x, y)^2 을 x로 두 번 미분하면 x > y 에서 2
+    let x = Tensor::new_with_label(3.0, "x");
+    let y = Tensor::new_with_label(1.0, "y");
+    let f = x.max(&y).clamp(-10.0, 10.0).pow(2.0);
+    let dx = f.backward_create_graph(std::slice::from_ref(&x)).remove(0);
+    assert_eq!(x.grad(), 6.0);
+    x.set_grad(0.0);
+    y.set_grad(0.0);
+    dx.backward();
+    assert_eq!(x.grad(), 2.0);
+    assert_eq!(y.grad(), 0.0);
+}
+
+fn every_op(t: &[Tensor]) -> Tensor {
+    let (x, y) = (&t[0], &t[1]);
+    let m = &x.max(y) + &x.min(&y.tanh());
+    let c = (x * y).clamp(-0.5, 0.5);
+    let w = Tensor::where_(&(x - y).relu(), &x.exp(), &(y * y));
+    &(&m + &c) + &w
+}
+
+#[test]
+fn test_program_symbolic_and_json() {
+    let mut program = Program::trace(2, |t| vec![every_op(t)]);
+    for (x, y) in [(0.7, -1.3), (-0.4, 2.1), (0.5, 0.5), (-2.0, -0.8)] {
+        let inputs = Tensor::from_vec(vec![x, y]);
+        inputs[0].set_label("x");
+        inputs[1].set_label("y");
+        let out = every_op(&inputs);
+        out.backward();
+        let expected = grads(&inputs);
+
+        // Program 재실행
+        program.forward(&[x, y]);
+        program.backward();
+        assert!((program.output(0) - out.data()).abs() < 1e-12);
+        for (a, b) in program.input_grads().iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12, "{} {}", a, b);
+        }
+
+        // 기호 미분
+        let vars = HashMap::from([("x".to_string(), x), ("y".to_string(), y)]);
+        assert!((out.to_expr().eval(&vars) - out.data()).abs() < 1e-12);
+        for (t, g) in inputs.iter().zip(&expected) {
+            assert!((out.symbolic_grad(t).eval(&vars) - g).abs() < 1e-12);
+        }
+
+        // 저장 후 다시 불러오기
+        let loaded: Tensor = Tensor::from_json(&out.to_json()).unwrap();
+        assert_eq!(loaded.to_json(), out.to_json());
+    }
+    println!("{}", every_op(&Tensor::from_vec(vec![1.0, 2.0])).to_expr());
+}
+
+#[test]
+fn test_parse() {
+    let x = Tensor::new_with_label(2.0, "x");
+    let y = Tensor::new_with_label(-1.0, "y");
+    let bindings = [x.clone(), y.clone()];
+    let eval = |src: &str| parse(src, &bindings).unwrap().data();
+    assert_eq!(eval("max(x, y)"), 2.0);
+    assert_eq!(eval("min(x, y) * 3"), -3.0);
+    assert_eq!(eval("clamp(x * 4, -1, 1.5)"), 1.5);
+    assert_eq!(eval("where(y, x, 0) + where(0, x, 10)"), 12.0);
+    let err = parse("clamp(x, 1, y)", &bindings).unwrap_err();
+    assert_eq!(err.message, "third argument of clamp must be a number");
+    let err = parse("clamp(x, 1, 0)", &bindings).unwrap_err();
+    assert_eq!(err.message, "clamp requires lo <= hi");
+}
+
+// micrograd demo의 SVM max-margin loss: sum(max(0, 1 - y * score))
+#[test]
+fn test_max_margin_loss() {
+    let mlp: MLP = MLP::new(2, vec![8, 1]);
+    let xs = [
+        [2.0, 1.0],
+        [1.5, 2.0],
+        [-1.0, -2.0],
+        [-2.0, -0.5],
+        [1.0, -0.2],
+        [-0.5, 0.3],
+    ];
+    let ys = [1.0, 1.0, -1.0, -1.0, 1.0, -1.0];
+    let zero = Tensor::constant(0.0);
+
+    let mut losses = vec![];
+    for _ in 0..50 {
+        let loss: Tensor = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| {
+                let score = mlp.forward(&Tensor::from_vec(x.to_vec())).remove(0);
+                zero.max(&(1.0 - &(&score * y)))
+            })
+            .sum();
+
+        let params = mlp.parameters().concat();
+        params.iter().for_each(|p| p.set_grad(0.0));
+        loss.backward();
+        for p in &params {
+            p.set_data(p.data() - 0.05 * p.grad());
+        }
+        losses.push(loss.data());
+    }
+
+    println!("{:?} -> {:?}", losses[0], losses[losses.len() - 1]);
+    assert!(losses[losses.len() - 1] < losses[0]);
+    let correct = xs
+        .iter()
+        .zip(ys)
+        .filter(|(x, y)| {
+            let score = mlp.forward(&Tensor::from_vec(x.to_vec()))[0].data();
+            score * y > 0.0
+        })
+        .count();
+    assert_eq!(correct, xs.len());
+}
@@ -0,0 +1,120 @@
+use rust_micrograd::{
+    engine::{Program, Tensor},
+    nn::MLP,
+};
+
+// 최적화 전후 forward/backward 결과가 같은지 확인
+fn check_same(f: fn(&[Tensor]) -> Tensor, n: usize, points: &[Vec<f64>]) -> Program {
+    let mut plain = Program::trace(n, |t| vec![f(t)]);
+    let mut optimized = Program::trace(n, |t| vec![f(t)]);
+    let report = optimized.optimize();
+    println!("{}", report);
+    assert_eq!(report.nodes_before, plain.node_count());
+    assert_eq!(report.nodes_after, optimized.node_count());
+
+    for p in points {
+        plain.forward(p);
+        plain.backward();
+        optimized.forward(p);
+        optimized.backward();
+        assert!((plain.output(0) - optimized.output(0)).abs() < 1e-12);
+        for (a, b) in plain.input_grads().iter().zip(optimized.input_grads()) {
+            assert!((a - b).abs() < 1e-12, "{} {}", a, b);
+        }
+    }
+    optimized
+}
+
+#[test]
+fn test_constant_folding() {
+    // (2 + 1) * 3 은 상수 하나로
+    let f = |t: &[Tensor]| &(&t[0] * 2.0) + &(&(&Tensor::constant(2.0) + 1.0) * 3.0);
+    let mut program = check_same(f, 1, &[vec![0.5], vec![-2.0]]);
+    program.forward(&[1.0]);
+    assert_eq!(program.output(0), 11.0);
+
+    let mut fresh = Program::trace(1, |t| vec![f(t)]);
+    let report = fresh.optimize();
+    assert_eq!(report.folded, 2);
+    // x, 2, 3(접은 상수), x*2, + 만 남음
+    assert_eq!(report.nodes_after, 5);
+    assert!(report.nodes_after < report.nodes_before);
+}
+
+#[test]
+fn test_identity() {
+    // Sum은 0에서 시작하므로 0 + a 가 생김
+    let f = |t: &[Tensor]| t.iter().map(|x| &(x * 1.0) * x).sum::<Tensor>();
+    let mut program = Program::trace(3, |t| vec![f(t)]);
+    let report = program.optimize();
+    println!("{}", report);
+    // 0 + a 하나, x * 1 세 개
+    assert_eq!(report.folded, 4);
+    assert_eq!(program.len(), 3 + 2);
+    check_same(f, 3, &[vec![1.0, 2.0, 3.0]]);
+}
+
+#[test]
+fn test_cse() {
+    let f = |t: &[Tensor]| {
+        let (x, y) = (&t[0], &t[1]);
+        &(x * y).tanh() + &(y * x).tanh()
+    };
+    let mut program = Program::trace(2, |t| vec![f(t)]);
+    let report = program.optimize();
+    println!("{}", report);
+    assert_eq!(report.merged, 2);
+    assert_eq!(program.len(), 3);
+    check_same(f, 2, &[vec![0.3, -1.2], vec![2.0, 0.5]]);
+
+    // 파라미터 같은 일반 leaf는 접지 않음
+    let w = Tensor::new(2.0);
+    let mut program = Program::trace(1, |t| vec![&(&w * 3.0) * &t[0]]);
+    let report = program.optimize();
+    assert_eq!(report.folded, 0);
+    program.forward(&[1.0]);
+    program.backward();
+    assert_eq!(w.grad(), 3.0);
+}
+
+#[test]
+fn test_fuse_div() {
+    let f = |t: &[Tensor]| &(&t[0] / &t[1]) + &(&t[1].pow(-1.0) * &t[0].exp());
+    let mut program = Program::trace(2, |t| vec![f(t)]);
+    let report = program.optimize();
+    println!("{}", report);
+    assert_eq!(report.fused, 2);
+    assert_eq!(report.removed, 1);
+    // x, y, exp, /, /, +
+    assert_eq!(report.nodes_after, 6);
+    check_same(f, 2, &[vec![1.5, -0.7], vec![-2.0, 3.0]]);
+}
+
+#[test]
+fn test_mlp() {
+    let mlp: MLP = MLP::new(3, vec![4, 4, 1]);
+    let loss = |t: &[Tensor]| {
+        let pred = mlp.forward(&t[..3].to_vec()).remove(0);
+        vec![(&pred - &t[3]).pow(2.0)]
+    };
+    let mut plain = Program::trace(4, loss);
+    let mut optimized = Program::trace(4, loss);
+    let report = optimized.optimize();
+    println!("{}", report);
+    // Neuron은 이미 dot 노드 하나로 계산하므로 줄어들 것이 없음
+    assert_eq!(report.nodes_after, report.nodes_before);
+
+    let input = [2.0, 3.0, -1.0, 1.0];
+    plain.forward(&input);
+    optimized.forward(&input);
+    assert!((plain.output(0) - optimized.output(0)).abs() < 1e-12);
+
+    let params = mlp.parameters().concat();
+    plain.backward();
+    let expected: Vec<f64> = params.iter().map(|p| p.grad()).collect();
+    params.iter().for_each(|p| p.set_grad(0.0));
+    optimized.backward();
+    for (e, p) in expected.iter().zip(&params) {
+        assert!((e - p.grad()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,132 @@
+use rust_micrograd::engine::{ParseError, Tensor, parse};
+
+fn neuron_leaves() -> Vec<Tensor> {
+    vec![
+        Tensor::new_with_label(2.0, "x1"),
+        Tensor::new_with_label(0.0, "x2"),
+        Tensor::new_with_label(-3.0, "w1"),
+        Tensor::new_with_label(1.0, "w2"),
+        Tensor::new_with_label(6.881373587019543, "b"),
+    ]
+}
+
+#[test]
+fn test_neuron() {
+    let leaves = neuron_leaves();
+    let o = parse("tanh(x1*w1 + x2*w2 + b)", &leaves).unwrap();
+    println!("{}", o.to_expr());
+    assert!((o.data() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    assert_eq!(o.graph_size(), 10);
+
+    // 변수는 bindings의 leaf 그대로이므로 기울기가 쌓임
+    o.backward();
+    let grads: Vec<f64> = leaves.iter().map(|t| t.grad()).collect();
+    let expected = [-1.5, 0.5, 1.0, 0.0, 0.5];
+    for (g, e) in grads.iter().zip(expected) {
+        assert!((g - e).abs() < 1e-6, "{} {}", g, e);
+    }
+}
+
+#[test]
+fn test_precedence() {
+    let x = Tensor::new_with_label(3.0, "x");
+    let y = Tensor::new_with_label(2.0, "y");
+    let bindings = [x.clone(), y.clone()];
+    let eval = |src: &str| parse(src, &bindings).unwrap().data();
+
+    assert_eq!(eval("x + y * 2"), 7.0);
+    assert_eq!(eval("(x + y) * 2"), 10.0);
+    assert_eq!(eval("x - y - 1"), 0.0);
+    assert_eq!(eval("x / y / 2"), 0.75);
+    assert_eq!(eval("-x^2"), -9.0);
+    assert_eq!(eval("2^3^2"), 512.0);
+    assert_eq!(eval("x ** y"), 9.0);
+    assert_eq!(eval("x^-1"), 1.0 / 3.0);
+    assert_eq!(eval("1.5e1 + .5 - 2E-1"), 15.3);
+    assert_eq!(eval("--x"), 3.0);
+
+    let z = parse("x^y", &bindings).unwrap();
+    z.backward();
+    assert_eq!(x.grad(), 2.0 * 3.0);
+    assert!((y.grad() - 9.0 * 3.0_f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn test_functions() {
+    let x = Tensor::new_with_label(0.5, "x");
+    let bindings = [x.clone()];
+    let cases: Vec<(&str, Tensor)> = vec![
+        ("exp(x)", x.exp()),
+        ("log(x)", x.log()),
+        ("ln(x)", x.log()),
+        ("sqrt(x)", x.sqrt()),
+        ("abs(-x)", (-&x).abs()),
+        ("sin(x) + cos(x)", &x.sin() + &x.cos()),
+        ("sigmoid(x)", x.sigmoid()),
+        ("relu(x - 1)", (&x - 1.0).relu()),
+        ("softplus(x)", x.softplus()),
+        ("gelu(x)", x.gelu()),
+        ("silu(x)", x.silu()),
+        ("leaky_relu(-x, 0.1)", (-&x).leaky_relu(0.1)),
+        ("elu(-x, 0.5)", (-&x).elu(0.5)),
+        ("pow(x, 3)", x.pow(3.0)),
+    ];
+    for (src, expected) in cases {
+        let t = parse(src, &bindings).unwrap();
+        assert_eq!(t.data(), expected.data(), "{}", src);
+        assert_eq!(t.to_expr(), expected.to_expr(), "{}", src);
+    }
+}
+
+#[test]
+fn test_constant_folding() {
+    let x = Tensor::new_with_label(1.0, "x");
+    // 숫자끼리는 미리 계산하므로 x * 6 노드 하나와 상수 하나
+    let t = parse("x * (2 * 3)", &[x]).unwrap();
+    assert_eq!(t.data(), 6.0);
+    assert_eq!(t.graph_size(), 3);
+
+    let c = parse("2 ^ 10 - 24", &[] as &[Tensor]).unwrap();
+    assert!(c.is_constant());
+    assert_eq!(c.data(), 1000.0);
+}
+
+#[test]
+fn test_errors() {
+    let bindings = neuron_leaves();
+    let err = |src: &str| parse(src, &bindings).unwrap_err();
+
+    assert_eq!(
+        err("x1 + y"),
+        ParseError {
+            message: "unknown variable y".into(),
+            position: 5
+        }
+    );
+    println!("{}", err("tanh(x1"));
+    assert_eq!(err("tanh(x1").position, 7);
+    assert_eq!(err("tanh(x1").message, "unexpected end of input");
+    assert_eq!(err("(x1 + b").message, "unexpected end of input");
+    assert_eq!(err("x1 + b)").message, "unexpected ')'");
+    assert_eq!(err("x1 $ b").position, 3);
+    assert_eq!(err("foo(x1, b)").message, "unknown function foo");
+    assert_eq!(
+        err("tanh(x1, b)").message,
+        "tanh takes 1 argument(s), got 2"
+    );
+    assert_eq!(
+        err("elu(x1, b)").message,
+        "second argument of elu must be a number"
+    );
+    assert_eq!(err("x1 * * b").message, "unexpected '*'");
+    assert_eq!(err("").position, 0);
+}
+
+#[test]
+fn test_f32() {
+    let x = Tensor::<f32>::leaf_with_label(2.0, "x");
+    let y = parse("x^2 / 4", std::slice::from_ref(&x)).unwrap();
+    y.backward();
+    assert_eq!(y.data(), 1.0_f32);
+    assert_eq!(x.grad(), 1.0_f32);
+}
@@ -0,0 +1,84 @@
+use rust_micrograd::{
+    engine::{Tensor, gradcheck},
+    nn::{MLP, Module},
+};
+
+#[test]
+fn test_f32_tensor() {
+    let x1 = Tensor::<f32>::leaf_with_label(2.0, "x1");
+    let w1 = Tensor::leaf_with_label(-3.0_f32, "w1");
+    let b = Tensor::leaf_with_label(6.881_373_6_f32, "b");
+
+    let o = (&(&x1 * &w1) + &b).tanh();
+    o.backward();
+    println!("o: {:?}", o);
+
+    let o_data: f32 = o.data();
+    assert!((o_data - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-5);
+    assert!((x1.grad() - -1.5).abs() < 1e-5);
+    assert!((w1.grad() - 1.0).abs() < 1e-5);
+
+    // 스칼라 연산도 같은 타입으로
+    let y = &(2.0_f32 * &(&x1 + 1.0)) - 0.5;
+    let y = 1.0_f32 - &y.pow(2.0);
+    assert_eq!(y.data(), 1.0 - 5.5 * 5.5);
+}
+
+#[test]
+fn test_f32_matches_f64() {
+    let values = [0.3, -1.2, 0.8];
+    let f32s = Tensor::<f32>::leaves(values.iter().map(|v| *v as f32).collect());
+    let f64s = Tensor::<f64>::leaves(values.to_vec());
+
+    let out32 = &(&f32s[0].sigmoid() * &f32s[1].gelu()) + &f32s[2].softplus().log();
+    let out64 = &(&f64s[0].sigmoid() * &f64s[1].gelu()) + &f64s[2].softplus().log();
+    out32.backward();
+    out64.backward();
+
+    assert!((out32.data() as f64 - out64.data()).abs() < 1e-5);
+    for (a, b) in f32s.iter().zip(&f64s) {
+        assert!((a.grad() as f64 - b.grad()).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_f32_gradcheck() {
+    let inputs = Tensor::<f32>::leaves(vec![0.7, -0.4]);
+    let report = gradcheck(|xs| (&xs[0] * &xs[1].exp()).tanh(), &inputs, 1e-2, 1e-2);
+    println!("{}", report);
+    assert!(report.passed());
+}
+
+#[test]
+fn test_f32_mlp() {
+    let xs = [
+        [2.0, 3.0, -1.0],
+        [3.0, -1.0, 0.5],
+        [0.5, 1.0, 1.0],
+        [1.0, 1.0, -1.0],
+    ];
+    let ys = [1.0_f32, -1.0, -1.0, 1.0];
+
+    let mlp = MLP::<f32>::new(3, vec![4, 4, 1]);
+    let mut losses = vec![];
+    for _ in 0..30 {
+        let loss: Tensor<f32> = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| (&mlp.forward(&Tensor::leaves(x.to_vec()))[0] - y).pow(2.0))
+            .sum();
+
+        for p in mlp.parameters().iter().flatten() {
+            p.set_grad(0.0);
+        }
+        loss.backward();
+        for p in mlp.parameters().iter().flatten() {
+            p.set_data(p.data() - 0.05 * p.grad());
+        }
+        losses.push(loss.data());
+    }
+    mlp.zero_grad();
+
+    println!("loss: {} -> {}", losses[0], losses[losses.len() - 1]);
+    assert!(losses[losses.len() - 1] < losses[0]);
+}
@@ -0,0 +1,154 @@
+use rust_micrograd::{
+    engine::{CustomOp, Program, Tensor, live_tensors},
+    nn::MLP,
+};
+
+// 모든 연산을 한 번씩 사용하는 함수
+fn every_op(t: &[Tensor]) -> Tensor {
+    let (x, y) = (&t[0], &t[1]);
+    let a = &(x * y) + &(-x);
+    let b = &x.pow(3.0) + &x.abs().pow(y);
+    let c = &(&x.tanh() + &y.exp()) + &(&x.relu() + &y.leaky_relu(0.1));
+    let d = &(&x.sigmoid() + &y.abs().log()) + &(&y.abs().sqrt() + &x.sin());
+    let e = &(&y.cos() + &x.softplus()) + &(&y.gelu() + &x.silu());
+    let f = &y.elu(0.5) + &Tensor::dot(&[a.clone(), b.clone()], &[c.clone(), x.clone()]);
+    &(&(&a + &b) + &(&c + &d)) + &(&e + &f)
+}
+
+const POINTS: [(f64, f64); 4] = [(0.7, -1.3), (-0.4, 2.1), (1.5, 0.3), (-2.0, -0.8)];
+
+#[test]
+fn test_replay_matches_eager() {
+    let mut program = Program::trace(2, |t| vec![every_op(t)]);
+    println!(
+        "{} instructions, {} slots",
+        program.len(),
+        program.num_slots()
+    );
+    assert_eq!(program.num_inputs(), 2);
+
+    for (x, y) in POINTS {
+        program.forward(&[x, y]);
+        program.backward();
+
+        let inputs = Tensor::from_vec(vec![x, y]);
+        let out = every_op(&inputs);
+        out.backward();
+
+        assert!((program.output(0) - out.data()).abs() < 1e-9);
+        for (g, t) in program.input_grads().iter().zip(&inputs) {
+            assert!((g - t.grad()).abs() < 1e-9, "{} {}", g, t.grad());
+        }
+    }
+}
+
+#[test]
+fn test_multiple_outputs() {
+    let mut program = Program::trace(2, |t| vec![&t[0] * &t[1], &t[0] + &t[1]]);
+    program.forward(&[3.0, 4.0]);
+    assert_eq!(program.outputs(), vec![12.0, 7.0]);
+
+    // 출력 합의 기울기
+    program.backward();
+    assert_eq!(program.input_grads(), &[5.0, 4.0]);
+}
+
+#[test]
+fn test_captured_parameters() {
+    let w = Tensor::new_with_label(0.5, "w");
+    let mut program = Program::trace(1, |t| vec![(&t[0] * &w).tanh()]);
+
+    program.forward(&[2.0]);
+    assert_eq!(program.output(0), 1.0_f64.tanh());
+    program.backward();
+    let expected = (1.0 - 1.0_f64.tanh().powi(2)) * 2.0;
+    assert!((w.grad() - expected).abs() < 1e-12);
+
+    // 파라미터 값을 바꾸면 다음 forward에 반영되고, 기울기는 누적
+    w.set_data(0.0);
+    program.forward(&[2.0]);
+    assert_eq!(program.output(0), 0.0);
+    program.backward();
+    assert!((w.grad() - (expected + 2.0)).abs() < 1e-12);
+}
+
+struct Square;
+
+impl CustomOp for Square {
+    fn forward(&self, inputs: &[f64]) -> f64 {
+        inputs[0] * inputs[0]
+    }
+
+    fn backward(&self, inputs: &[f64], _output: f64, out_grad: f64) -> Vec<f64> {
+        vec![2.0 * inputs[0] * out_grad]
+    }
+}
+
+#[test]
+fn test_custom_op() {
+    let mut program = Program::trace(1, |t| vec![Tensor::apply(Square, t).exp()]);
+    program.forward(&[0.5]);
+    program.backward();
+    assert_eq!(program.output(0), 0.25_f64.exp());
+    assert_eq!(program.input_grads()[0], 0.25_f64.exp() * 1.0);
+}
+
+#[test]
+fn test_training_replay() {
+    let mlp: MLP = MLP::new(3, vec![4, 4, 1]);
+    let xs = [
+        [2.0, 3.0, -1.0],
+        [3.0, -1.0, 0.5],
+        [0.5, 1.0, 1.0],
+        [1.0, 1.0, -1.0],
+    ];
+    let ys = [1.0, -1.0, -1.0, 1.0];
+
+    // 입력 3개 + 정답 1개를 slot으로 하는 loss
+    let mut program = Program::trace(4, |t| {
+        let pred = mlp.forward(&t[..3].to_vec()).remove(0);
+        vec![(&pred - &t[3]).pow(2.0)]
+    });
+    let params = mlp.parameters().concat();
+
+    // 같은 파라미터에서 eager 결과와 같은 기울기
+    program.forward(&[2.0, 3.0, -1.0, 1.0]);
+    program.backward();
+    let replay: Vec<f64> = params.iter().map(|p| p.grad()).collect();
+    params.iter().for_each(|p| p.set_grad(0.0));
+    let x = Tensor::from_vec(xs[0].to_vec());
+    let loss = (&mlp.forward(&x)[0] - 1.0).pow(2.0);
+    loss.backward();
+    for (a, p) in replay.iter().zip(&params) {
+        assert!((a - p.grad()).abs() < 1e-12);
+    }
+    drop((x, loss));
+
+    let before = live_tensors();
+    let mut losses = Vec::new();
+    for _ in 0..50 {
+        params.iter().for_each(|p| p.set_grad(0.0));
+        let mut total = 0.0;
+        for (x, y) in xs.iter().zip(ys) {
+            program.forward(&[x[0], x[1], x[2], y]);
+            program.backward();
+            total += program.output(0);
+        }
+        for p in &params {
+            p.set_data(p.data() - 0.05 * p.grad());
+        }
+        losses.push(total);
+    }
+
+    // 반복하는 동안 Tensor 노드를 새로 만들지 않음
+    assert_eq!(live_tensors(), before);
+    println!("{:?} -> {:?}", losses[0], losses[losses.len() - 1]);
+    assert!(losses[losses.len() - 1] < losses[0]);
+}
+
+#[test]
+#[should_panic(expected = "wrong number of inputs")]
+fn test_wrong_inputs() {
+    let mut program = Program::trace(2, |t| vec![&t[0] + &t[1]]);
+    program.forward(&[1.0]);
+}
@@ -0,0 +1,220 @@
+use rust_micrograd::engine::{CustomOp, Tensor};
+
+fn neuron() -> (Vec<Tensor>, Tensor) {
+    let x1 = Tensor::new_with_label(2.0, "x1");
+    let w1 = Tensor::new_with_label(-3.0, "w1");
+    let b = Tensor::new_with_label(6.881373587019543, "b");
+    let n = &(&x1 * &w1) + &b;
+    n.set_label("n");
+    let o = n.tanh();
+    o.set_label("o");
+    (vec![x1, w1, b], o)
+}
+
+// label로 leaf 찾기
+fn find(root: &Tensor, label: &str) -> Tensor {
+    root.topological_sort()
+        .into_iter()
+        .find(|t| t.label() == label)
+        .unwrap()
+}
+
+#[test]
+fn test_snapshot() {
+    let x = Tensor::new_with_label(3.0, "x");
+    let y = &x.pow(2.0) + 1.0;
+    y.backward();
+    let json = y.to_json();
+    println!("{}", json);
+    assert_eq!(
+        json,
+        r#"{
+  "version": 1,
+  "root": 3,
+  "nodes": [
+    {"id": 0, "op": "leaf", "label": "x", "data": 3, "grad": 6, "prev": []},
+    {"id": 1, "op": "powf", "arg": 2, "label": "", "data": 9, "grad": 1, "prev": [0]},
+    {"id": 2, "op": "constant", "label": "", "data": 1, "grad": 1, "prev": []},
+    {"id": 3, "op": "+", "label": "", "data": 10, "grad": 1, "prev": [1, 2]}
+  ]
+}
+"#
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let (_, o) = neuron();
+    o.backward();
+    let json = o.to_json();
+
+    let loaded = Tensor::from_json(&json).unwrap();
+    assert_eq!(loaded.to_json(), json);
+    assert_eq!(loaded.data(), o.data());
+    assert_eq!(loaded.to_expr(), o.to_expr());
+
+    // 불러온 그래프에서 다시 backward
+    let leaves: Vec<Tensor> = ["x1", "w1", "b"].iter().map(|l| find(&loaded, l)).collect();
+    let saved: Vec<f64> = leaves.iter().map(|t| t.grad()).collect();
+    loaded
+        .topological_sort()
+        .iter()
+        .for_each(|t| t.set_grad(0.0));
+    loaded.backward();
+    assert_eq!(saved, leaves.iter().map(|t| t.grad()).collect::<Vec<_>>());
+    assert!((find(&loaded, "x1").grad() - -1.5).abs() < 1e-6);
+
+    // leaf 값을 바꾸면 다시 계산할 수 있는 live 그래프
+    let w1 = find(&loaded, "w1");
+    assert!(w1.is_leaf() && !w1.is_constant());
+}
+
+#[test]
+fn test_shared_nodes() {
+    let x = Tensor::new_with_label(1.5, "x");
+    let h = (&x * &x).tanh();
+    let y = &(&h * &h) + &h;
+    let loaded = Tensor::from_json(&y.to_json()).unwrap();
+    assert_eq!(loaded.graph_size(), y.graph_size());
+    assert_eq!(loaded.graph_stats(), y.graph_stats());
+
+    y.backward();
+    loaded.backward();
+    assert_eq!(find(&loaded, "x").grad(), x.grad());
+}
+
+#[test]
+fn test_every_op() {
+    let x = Tensor::new_with_label(0.7, "x");
+    let y = Tensor::new_with_label(-1.3, "y");
+    let a = &(&x * &y) + &(-&x);
+    let b = &x.pow(3.0) + &x.abs().pow(&y);
+    let c = &(&x.tanh() + &y.exp()) + &(&x.relu() + &y.leaky_relu(0.1));
+    let d = &(&x.sigmoid() + &y.abs().log()) + &(&y.abs().sqrt() + &x.sin());
+    let e = &(&y.cos() + &x.softplus()) + &(&y.gelu() + &x.silu());
+    let f = &y.elu(0.5) + &Tensor::dot(&[a.clone(), b.clone()], &[c.clone(), x.clone()]);
+    let z = &(&(&a + &b) + &(&c + &d)) + &(&e + &f);
+
+    let loaded = Tensor::from_json(&z.to_json()).unwrap();
+    assert_eq!(loaded.data(), z.data());
+    z.backward();
+    loaded.backward();
+    for label in ["x", "y"] {
+        assert_eq!(find(&loaded, label).grad(), find(&z, label).grad());
+    }
+}
+
+#[test]
+fn test_special_values() {
+    let x = Tensor::new_with_label(f64::INFINITY, "say \"hi\"\n\t\\ 안녕");
+    let y = &(&x * 0.0) + &Tensor::new(-0.0);
+    let json = y.to_json();
+    println!("{}", json);
+    assert!(json.contains("\"NaN\""));
+    assert!(json.contains("\"inf\""));
+
+    let loaded: Tensor = Tensor::from_json(&json).unwrap();
+    assert!(loaded.data().is_nan());
+    let x = &loaded.prev()[0].prev()[0];
+    assert_eq!(x.label(), "say \"hi\"\n\t\\ 안녕");
+    assert_eq!(x.data(), f64::INFINITY);
+    assert_eq!(loaded.to_json(), json);
+}
+
+#[test]
+fn test_f32() {
+    let x = Tensor::<f32>::leaf_with_label(0.1, "x");
+    let y = x.exp();
+    let loaded = Tensor::<f32>::from_json(&y.to_json()).unwrap();
+    assert_eq!(loaded.data(), y.data());
+    assert_eq!(loaded.prev()[0].data(), 0.1_f32);
+}
+
+struct Square;
+
+impl CustomOp for Square {
+    fn name(&self) -> &str {
+        "square"
+    }
+
+    fn forward(&self, inputs: &[f64]) -> f64 {
+        inputs[0] * inputs[0]
+    }
+
+    fn backward(&self, inputs: &[f64], _output: f64, out_grad: f64) -> Vec<f64> {
+        vec![2.0 * inputs[0] * out_grad]
+    }
+}
+
+#[test]
+fn test_errors() {
+    let x = Tensor::new(2.0);
+    let custom = Tensor::apply(Square, &[x]);
+    let err = Tensor::<f64>::from_json(&custom.to_json()).unwrap_err();
+    println!("{}", err);
+    assert_eq!(err.message, "custom op square cannot be loaded");
+
+    let load = |src: &str| Tensor::<f64>::from_json(src).unwrap_err();
+    assert_eq!(load("").message, "unexpected end of input");
+    assert_eq!(load("{\"version\": 1,}").position, 14);
+    assert_eq!(load("[]").message, "missing field version");
+    assert_eq!(load("{\"version\": 2}").message, "unsupported version 2");
+
+    let node = r#"{"id": 0, "op": "tanh", "label": "", "data": 0, "grad": 0, "prev": [3]}"#;
+    let src = format!("{{\"version\": 1, \"root\": 0, \"nodes\": [{}]}}", node);
+    assert_eq!(load(&src).message, "unknown node 3");
+    let src = src.replace("[3]", "[]");
+    assert_eq!(load(&src).message, "tanh takes 1 input(s), found 0");
+    let src = src
+        .replace("tanh", "leaf")
+        .replace("\"root\": 0", "\"root\": 1");
+    assert_eq!(load(&src).message, "unknown node 1");
+
+    // matvec의 행 수, row의 위치는 범위 안의 정수여야 함
+    let matvec = |arg: &str, prev: &str| {
+        let node = format!(
+            r#"{{"id": 0, "op": "leaf", "label": "", "data": 1, "grad": 0, "prev": []}},
+            {{"id": 1, "op": "matvec", "arg": {}, "label": "", "data": 0, "grad": 0, "prev": [{}]}}"#,
+            arg, prev
+        );
+        load(&format!(
+            "{{\"version\": 1, \"root\": 1, \"nodes\": [{}]}}",
+            node
+        ))
+        .message
+    };
+    assert_eq!(
+        matvec("1e30", ""),
+        "matvec requires an integer arg in 0..=0, found 1000000000000000000000000000000"
+    );
+    assert_eq!(
+        matvec("-1", "0"),
+        "matvec requires an integer arg in 0..=1, found -1"
+    );
+    assert_eq!(
+        matvec("0.5", "0"),
+        "matvec requires an integer arg in 0..=1, found 0.5"
+    );
+    assert_eq!(
+        matvec("\"NaN\"", "0"),
+        "matvec requires an integer arg in 0..=1, found NaN"
+    );
+    assert_eq!(matvec("1", "0, 0, 0"), "matvec inputs do not match 1 rows");
+
+    let row = |arg: &str| {
+        let node = format!(
+            r#"{{"id": 0, "op": "leaf", "label": "", "data": 1, "grad": 0, "prev": []}},
+            {{"id": 1, "op": "matvec", "arg": 1, "label": "", "data": 0, "grad": 0, "prev": [0, 0]}},
+            {{"id": 2, "op": "row", "arg": {}, "label": "", "data": 0, "grad": 0, "prev": [1]}}"#,
+            arg
+        );
+        load(&format!(
+            "{{\"version\": 1, \"root\": 2, \"nodes\": [{}]}}",
+            node
+        ))
+        .message
+    };
+    assert!(row("1e30").starts_with("row requires an integer arg"));
+    assert!(row("-1").starts_with("row requires an integer arg"));
+    assert_eq!(row("1"), "row 1 is not an output of a matvec node");
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use rust_micrograd::{
+    engine::{Program, Tensor, gradcheck, log_softmax, logsumexp, parse, softmax},
+    nn::MLP,
+};
+
+fn labeled(values: &[f64]) -> Vec<Tensor> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| Tensor::new_with_label(*v, &format!("x{}", i)))
+        .collect()
+}
+
+#[test]
+fn test_values() {
+    let xs = labeled(&[1.0, 2.0, 3.0]);
+    let s: Vec<f64> = softmax(&xs).iter().map(|t| t.data()).collect();
+    let total: f64 = [1.0_f64, 2.0, 3.0].iter().map(|x| x.exp()).sum();
+    for (i, v) in s.iter().enumerate() {
+        assert!((v - ((i + 1) as f64).exp() / total).abs() < 1e-15);
+    }
+    assert!((s.iter().sum::<f64>() - 1.0).abs() < 1e-15);
+
+    let lse = logsumexp(&xs).data();
+    assert!((lse - total.ln()).abs() < 1e-14);
+    for (l, v) in log_softmax(&xs).iter().zip(&s) {
+        assert!((l.data() - v.ln()).abs() < 1e-14);
+    }
+}
+
+#[test]
+fn test_large_logits() {
+    let xs = labeled(&[1000.0, 1001.0, 1002.0]);
+
+    // exp, Sum, pow(-1) 로 만들면 inf / inf
+    let exps: Vec<Tensor> = xs.iter().map(|x| x.exp()).collect();
+    let total: Tensor = exps.iter().cloned().sum();
+    let naive = &exps[0] * &total.pow(-1.0);
+    assert!(naive.data().is_nan());
+
+    // exp(x_i - lse) 로 계산하므로 lse(~1002)의 반올림 오차만큼은 차이가 남
+    let s = softmax(&xs);
+    let shifted = softmax(&labeled(&[0.0, 1.0, 2.0]));
+    for (a, b) in s.iter().zip(&shifted) {
+        assert!((a.data() - b.data()).abs() < 1e-12);
+    }
+    assert!(
+        (logsumexp(&xs).data() - (1002.0 + logsumexp(&labeled(&[-2.0, -1.0, 0.0])).data())).abs()
+            < 1e-12
+    );
+
+    let loss = -&log_softmax(&xs)[0];
+    loss.backward();
+    assert!(xs.iter().all(|x| x.grad().is_finite()));
+    assert!((xs[0].grad() - (s[0].data() - 1.0)).abs() < 1e-15);
+}
+
+#[test]
+fn test_shared_node() {
+    let xs = labeled(&[0.5, -1.0, 2.0, 0.1]);
+    // 출력 하나만 쓰면 입력 4개 + logsumexp 1개 + 출력 1개
+    let picked = log_softmax(&xs).swap_remove(2);
+    assert_eq!(picked.graph_size(), 6);
+    assert_eq!(logsumexp(&xs).graph_size(), 5);
+
+    // 출력은 모두 x_i와 같은 logsumexp 노드 하나만 가리킴
+    let s = softmax(&xs);
+    let lse = s[0].prev()[1].clone();
+    for (o, x) in s.iter().zip(&xs) {
+        assert_eq!(o.prev(), vec![x.clone(), lse.clone()]);
+    }
+    // 출력 전부를 더해도 입력 4개 + logsumexp 1개 + 출력 4개 + (상수 0 + 덧셈 4개)
+    let total: Tensor = s.iter().cloned().sum();
+    assert_eq!(total.graph_size(), 14);
+    total.backward();
+    assert!(xs.iter().all(|x| x.grad().abs() < 1e-15));
+}
+
+// 입력 전체를 받아서 출력 여러 개를 만드는 함수
+type Outputs = fn(&[Tensor]) -> Vec<Tensor>;
+
+#[test]
+fn test_gradcheck() {
+    let xs = labeled(&[0.3, -1.2, 2.0, 0.7]);
+    let weights = [0.5, -2.0, 1.5, 3.0];
+    let fs: [Outputs; 3] = [softmax, log_softmax, |t| vec![logsumexp(t)]];
+    for f in fs {
+        let loss = |t: &[Tensor]| f(t).iter().zip(weights).map(|(o, w)| o * w).sum::<Tensor>();
+        let report = gradcheck(loss, &xs, 1e-6, 1e-6);
+        println!("{}", report);
+        assert!(report.passed());
+    }
+}
+
+#[test]
+fn test_second_order() {
+    // logsumexp의 Hessian = diag(s) - s s^T
+    let xs = labeled(&[0.2, -0.4, 1.1]);
+    let s: Vec<f64> = softmax(&xs).iter().map(|t| t.data()).collect();
+    let g0 = logsumexp(&xs).backward_create_graph(&xs).remove(0);
+    xs.iter().for_each(|x| x.set_grad(0.0));
+    g0.backward();
+    for (j, x) in xs.iter().enumerate() {
+        let expected = if j == 0 { s[0] } else { 0.0 } - s[0] * s[j];
+        assert!(
+            (x.grad() - expected).abs() < 1e-12,
+            "{} {}",
+            x.grad(),
+            expected
+        );
+    }
+}
+
+fn loss(t: &[Tensor]) -> Tensor {
+    let s = softmax(&t[..3]);
+    let l = log_softmax(&t[..3]);
+    &(&(&s[0] * &t[3]) + &l[2]) + &logsumexp(&[t[3].clone(), t[0].clone()])
+}
+
+#[test]
+fn test_program_symbolic_and_json() {
+    let mut program = Program::trace(4, |t| vec![loss(t)]);
+    for point in [[0.3, -1.2, 2.0, 0.7], [5.0, 1.0, -3.0, 2.0]] {
+        let inputs = labeled(&point);
+        let out = loss(&inputs);
+        out.backward();
+
+        program.forward(&point);
+        program.backward();
+        assert!((program.output(0) - out.data()).abs() < 1e-12);
+        for (g, t) in program.input_grads().iter().zip(&inputs) {
+            assert!((g - t.grad()).abs() < 1e-12);
+        }
+
+        let vars: HashMap<String, f64> = inputs.iter().map(|t| (t.label(), t.data())).collect();
+        assert!((out.to_expr().eval(&vars) - out.data()).abs() < 1e-12);
+        for t in &inputs {
+            assert!((out.symbolic_grad(t).eval(&vars) - t.grad()).abs() < 1e-12);
+        }
+
+        let loaded: Tensor = Tensor::from_json(&out.to_json()).unwrap();
+        assert_eq!(loaded.to_json(), out.to_json());
+    }
+    println!("{}", logsumexp(&labeled(&[1.0, 2.0])).to_expr());
+
+    let xs = labeled(&[1.0, 2.0, 3.0]);
+    let parsed = parse("logsumexp(x0, x1, x2) - x0", &xs).unwrap();
+    assert!((parsed.data() - -log_softmax(&xs)[0].data()).abs() < 1e-15);
+}
+
+#[test]
+#[should_panic(expected = "softmax requires at least one input")]
+fn test_empty() {
+    softmax::<f64>(&[]);
+}
+
+// 3개 클래스 분류, cross entropy = -log_softmax(logits)[target]
+#[test]
+fn test_classification() {
+    let mlp: MLP = MLP::new(2, vec![8, 3]);
+    let xs = [
+        [2.0, 0.0],
+        [1.5, 0.5],
+        [-1.0, 2.0],
+        [-0.5, 1.5],
+        [0.0, -2.0],
+        [0.5, -1.5],
+    ];
+    let ys = [0, 0, 1, 1, 2, 2];
+
+    let mut losses = vec![];
+    for _ in 0..200 {
+        let loss: Tensor = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| {
+                // tanh 출력 [-1, 1]을 키워서 logit으로 사용
+                let logits: Vec<Tensor> = mlp
+                    .forward(&Tensor::from_vec(x.to_vec()))
+                    .iter()
+                    .map(|o| o * 4.0)
+                    .collect();
+                -&log_softmax(&logits)[y]
+            })
+            .sum();
+
+        let params = mlp.parameters().concat();
+        params.iter().for_each(|p| p.set_grad(0.0));
+        loss.backward();
+        for p in &params {
+            p.set_data(p.data() - 0.05 * p.grad());
+        }
+        losses.push(loss.data());
+    }
+
+    println!("{:?} -> {:?}", losses[0], losses[losses.len() - 1]);
+    assert!(losses[losses.len() - 1] < losses[0] * 0.5);
+    let correct = xs
+        .iter()
+        .zip(ys)
+        .filter(|(x, y)| {
+            let probs = softmax(&mlp.forward(&Tensor::from_vec(x.to_vec())));
+            let best = (0..3)
+                .max_by(|a, b| probs[*a].data().total_cmp(&probs[*b].data()))
+                .unwrap();
+            best == *y
+        })
+        .count();
+    println!("{} / {}", correct, xs.len());
+    assert_eq!(correct, xs.len());
+}
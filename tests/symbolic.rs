@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use rust_micrograd::engine::{Expr, Func, Tensor};
+
+fn neuron() -> (Vec<Tensor>, Tensor) {
+    let x1 = Tensor::new_with_label(2.0, "x1");
+    let x2 = Tensor::new_with_label(0.0, "x2");
+    let w1 = Tensor::new_with_label(-3.0, "w1");
+    let w2 = Tensor::new_with_label(1.0, "w2");
+    let b = Tensor::new_with_label(6.881373587019543, "b");
+    let n = &(&(&x1 * &w1) + &(&x2 * &w2)) + &b;
+    let o = n.tanh();
+    (vec![x1, x2, w1, w2, b], o)
+}
+
+fn bindings(leaves: &[Tensor]) -> HashMap<String, f64> {
+    leaves.iter().map(|t| (t.label(), t.data())).collect()
+}
+
+#[test]
+fn test_to_expr() {
+    let (leaves, o) = neuron();
+    let expr = o.to_expr();
+    println!("{}", expr);
+    assert_eq!(expr.to_string(), "tanh(x1 * w1 + x2 * w2 + b)");
+    assert!((expr.eval(&bindings(&leaves)) - o.data()).abs() < 1e-12);
+
+    println!("{}", expr.to_latex());
+    assert_eq!(
+        expr.to_latex(),
+        "\\tanh\\left(x_{1} \\cdot w_{1} + x_{2} \\cdot w_{2} + b\\right)"
+    );
+}
+
+#[test]
+fn test_parentheses() {
+    let x = Tensor::new_with_label(1.5, "x");
+    let y = Tensor::new_with_label(-0.5, "y");
+    let z = &(&(&x + &y) * &(&x - &y)) / &y.exp();
+    println!("{}", z.to_expr());
+    assert_eq!(z.to_expr().to_string(), "(x + y) * (x - y) / exp(y)");
+    assert_eq!(
+        z.to_expr().to_latex(),
+        "\\frac{\\left(x + y\\right) \\cdot \\left(x - y\\right)}{e^{y}}"
+    );
+
+    // 라벨이 없는 leaf는 값으로 표시
+    let w = &(&x * 2.0) - &Tensor::new(3.0).pow(2.0);
+    assert_eq!(w.to_expr().to_string(), "2 * x - 9");
+    assert_eq!((-&(&x - &y)).to_expr().to_string(), "y - x");
+    assert_eq!(x.pow(-2.0).to_expr().to_string(), "x^(-2)");
+}
+
+#[test]
+fn test_symbolic_grad_neuron() {
+    let (leaves, o) = neuron();
+    o.backward();
+    let vars = bindings(&leaves);
+
+    let dw1 = o.symbolic_grad(&leaves[2]);
+    println!("do/dw1 = {}", dw1);
+    assert_eq!(dw1.to_string(), "(1 - tanh(x1 * w1 + x2 * w2 + b)^2) * x1");
+    for leaf in &leaves {
+        let g = o.symbolic_grad(leaf).eval(&vars);
+        assert!((g - leaf.grad()).abs() < 1e-12, "{} {}", g, leaf.grad());
+    }
+}
+
+#[test]
+fn test_simplify() {
+    let x = Tensor::new_with_label(0.7, "x");
+    let y = Tensor::new_with_label(-1.1, "y");
+
+    // 상관없는 변수에 대한 미분은 0
+    assert_eq!(x.exp().symbolic_grad(&y), Expr::Const(0.0));
+    assert_eq!(x.symbolic_grad(&x), Expr::Const(1.0));
+    // x + x + x -> 3
+    assert_eq!((&(&x + &x) + &x).symbolic_grad(&x), Expr::Const(3.0));
+    // d(x * y)/dx = y
+    assert_eq!((&x * &y).symbolic_grad(&x), Expr::var("y"));
+    assert_eq!(x.pow(3.0).symbolic_grad(&x).to_string(), "3 * x^2");
+    assert_eq!((&x / &y).symbolic_grad(&x).to_string(), "1 / y");
+    // 분자와 분모의 공통 인수는 약분
+    let f = &(&(&x * &y) + 1.0).tanh() / &y;
+    assert_eq!(f.symbolic_grad(&x).to_string(), "1 - tanh(x * y + 1)^2");
+    assert_eq!(x.sin().symbolic_grad(&x).to_string(), "cos(x)");
+    assert_eq!(x.cos().symbolic_grad(&x).to_string(), "-sin(x)");
+    assert_eq!(
+        x.relu().symbolic_grad(&x),
+        Expr::func(Func::Step, Expr::var("x"))
+    );
+
+    let e = (Expr::var("a") * Expr::Const(1.0) + Expr::Const(0.0)) * Expr::Const(2.0);
+    assert_eq!(e.to_string(), "2 * a");
+    assert_eq!(-(-Expr::var("a")), Expr::var("a"));
+    assert_eq!(Expr::var("a") - Expr::var("a"), Expr::Const(0.0));
+    let (a, b, c) = (Expr::var("a"), Expr::var("b"), Expr::var("c"));
+    assert_eq!((a.clone() * b.clone() / b.clone()).to_string(), "a");
+    assert_eq!((b.clone() * a.clone() / b.clone()).to_string(), "a");
+    assert_eq!(
+        (a.clone() * b.clone() / (b.clone() * c.clone())).to_string(),
+        "a / c"
+    );
+    assert_eq!((b.clone() / (b * c)).to_string(), "1 / c");
+    assert_eq!(Expr::func(Func::Exp, Expr::Const(0.0)), Expr::Const(1.0));
+}
+
+// 모든 연산의 미분 식이 backward와 같은지
+#[test]
+fn test_symbolic_grad_every_op() {
+    let fs: Vec<fn(&Tensor, &Tensor) -> Tensor> = vec![
+        |x, y| &(x * y) + &(-x),
+        |x, y| &x.pow(3.0) + &x.abs().pow(y),
+        |x, y| &(&x.tanh() + &y.exp()) + &(&x.relu() + &y.leaky_relu(0.1)),
+        |x, y| &(&x.sigmoid() + &y.abs().log()) + &(&y.abs().sqrt() + &x.sin()),
+        |x, y| &(&y.cos() + &x.softplus()) + &(&y.gelu() + &x.silu()),
+        |x, y| &y.elu(0.5) + &Tensor::dot(&[x.clone(), y.clone()], &[y.exp(), x.tanh()]),
+        |x, y| &(x / y) * &(x - y),
+    ];
+    for (x, y) in [(0.7, -1.3), (-0.4, 2.1), (1.5, 0.3)] {
+        let x = Tensor::new_with_label(x, "x");
+        let y = Tensor::new_with_label(y, "y");
+        let vars = bindings(&[x.clone(), y.clone()]);
+        for f in &fs {
+            x.set_grad(0.0);
+            y.set_grad(0.0);
+            let z = f(&x, &y);
+            z.backward();
+            assert!((z.to_expr().eval(&vars) - z.data()).abs() < 1e-12);
+            for t in [&x, &y] {
+                let g = z.symbolic_grad(t).eval(&vars);
+                assert!(
+                    (g - t.grad()).abs() < 1e-9,
+                    "{}: {} {}",
+                    z.to_expr(),
+                    g,
+                    t.grad()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{iter::zip, thread};
+
+use rust_micrograd::sync::{MLP, Tensor};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_send_sync() {
+    assert_send_sync::<Tensor>();
+    assert_send_sync::<MLP>();
+}
+
+#[test]
+fn test_backward() {
+    let x1 = Tensor::new_with_label(2.0, "x1");
+    let x2 = Tensor::new_with_label(0.0, "x2");
+    let w1 = Tensor::new_with_label(-3.0, "w1");
+    let w2 = Tensor::new_with_label(1.0, "w2");
+    let b = Tensor::new_with_label(6.881373587019543, "b");
+
+    let o = (&(&x1 * &w1) + &(&x2 * &w2) + &b).tanh();
+    o.backward();
+    println!("o: {:?}", o);
+
+    assert!((o.data() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+    assert!((x1.grad() - -1.5).abs() < 1e-6);
+    assert!((w1.grad() - 1.0).abs() < 1e-6);
+    assert!((x2.grad() - 0.5).abs() < 1e-6);
+    assert!((b.grad() - 0.5).abs() < 1e-6);
+
+    // 같은 노드를 두 번 사용
+    let a = Tensor::new(3.0);
+    let c = &(&a * &a) / &a.exp() - 1.0;
+    c.backward();
+    let expected = (2.0 * 3.0 - 9.0) / 3.0_f64.exp();
+    assert!((a.grad() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_parallel_gradients() {
+    let xs: Vec<Vec<f64>> = (0..16)
+        .map(|i| {
+            let i = i as f64;
+            vec![(i * 0.3).sin(), (i * 0.7).cos(), i / 16.0 - 0.5]
+        })
+        .collect();
+    let ys: Vec<f64> = (0..16)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+
+    let mlp = MLP::new(3, vec![4, 4, 1]);
+    let loss = |x: &[f64], y: f64| (&mlp.forward(&Tensor::from_vec(x.to_vec()))[0] - y).pow(2.0);
+
+    // 한 thread에서 순서대로
+    for (x, y) in zip(&xs, &ys) {
+        loss(x, *y).backward();
+    }
+    let sequential: Vec<f64> = mlp
+        .parameters()
+        .iter()
+        .flatten()
+        .map(|p| p.grad())
+        .collect();
+
+    // sample마다 worker thread에서 forward/backward, 기울기는 공유 파라미터에 누적
+    mlp.zero_grad();
+    let samples: Vec<(&Vec<f64>, &f64)> = zip(&xs, &ys).collect();
+    thread::scope(|s| {
+        for chunk in samples.chunks(4) {
+            let loss = &loss;
+            s.spawn(move || {
+                for (x, y) in chunk {
+                    loss(x, **y).backward();
+                }
+            });
+        }
+    });
+    let parallel: Vec<f64> = mlp
+        .parameters()
+        .iter()
+        .flatten()
+        .map(|p| p.grad())
+        .collect();
+
+    assert_eq!(sequential.len(), 4 * 4 + 4 * 5 + 5);
+    for (s, p) in zip(sequential, parallel) {
+        assert!((s - p).abs() < 1e-9, "{} != {}", s, p);
+    }
+}
+
+#[test]
+fn test_deep_chain() {
+    let x = Tensor::new(0.5);
+    let mut y = x.clone();
+    for _ in 0..200_000 {
+        y = &y + 1.0;
+    }
+    y.backward();
+    assert_eq!(x.grad(), 1.0);
+}
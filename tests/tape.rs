@@ -0,0 +1,134 @@
+use rust_micrograd::{
+    engine::{Tape, Tensor, Var},
+    nn::MLP,
+};
+
+#[test]
+fn test_tape() {
+    let tape = Tape::new();
+    let x1 = tape.var(2.0);
+    let x2 = tape.var(0.0);
+    let w1 = tape.var(-3.0);
+    let w2 = tape.var(1.0);
+    let b = tape.var(6.881373587019543);
+
+    let o = (x1 * w1 + x2 * w2 + b).tanh();
+    o.backward();
+    println!("o: {:?}", o);
+
+    assert!((o.data() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+    assert!((x1.grad() - -1.5).abs() < 1e-9);
+    assert!((w1.grad() - 1.0).abs() < 1e-9);
+    assert!((x2.grad() - 0.5).abs() < 1e-9);
+    assert!((w2.grad() - 0.0).abs() < 1e-9);
+    assert!((b.grad() - 0.5).abs() < 1e-9);
+    // leaf 5개 + 연산 5개
+    assert_eq!(tape.len(), 10);
+}
+
+#[test]
+fn test_matches_tensor() {
+    let values = [1.3, -0.4, 0.7];
+
+    let ts = Tensor::from_vec(values.to_vec());
+    let out = &(&(&ts[0] * &ts[1]).exp() / &(&ts[2].sigmoid() + 2.0)) - &(3.0 - &ts[0]).pow(2.0);
+    let out = &out + &(&ts[1].sin() * &ts[2].relu());
+    out.backward();
+
+    let tape = Tape::new();
+    let vs = tape.vars(&values);
+    let o = (vs[0] * vs[1]).exp() / (vs[2].sigmoid() + 2.0) - (3.0 - vs[0]).pow(2.0)
+        + vs[1].sin() * vs[2].relu();
+    o.backward();
+
+    assert!((o.data() - out.data()).abs() < 1e-12);
+    for (v, t) in vs.iter().zip(&ts) {
+        assert!((v.grad() - t.grad()).abs() < 1e-12);
+    }
+}
+
+// nn::MLP와 같은 구조를 tape 위에서 forward
+// params는 neuron마다 [w..., b] 순서로 펼친 파라미터
+fn forward<'t>(params: &[Var<'t>], sizes: &[usize], x: &[Var<'t>]) -> Vec<Var<'t>> {
+    let mut acts = x.to_vec();
+    let mut k = 0;
+    for &n_out in sizes {
+        acts = (0..n_out)
+            .map(|_| {
+                let n_in = acts.len();
+                let (ws, b) = (&params[k..k + n_in], params[k + n_in]);
+                k += n_in + 1;
+                ws.iter()
+                    .zip(&acts)
+                    .fold(b, |acc, (w, a)| acc + *w * *a)
+                    .tanh()
+            })
+            .collect();
+    }
+    acts
+}
+
+#[test]
+fn test_mlp_training() {
+    let xs = [
+        [2.0, 3.0, -1.0],
+        [3.0, -1.0, 0.5],
+        [0.5, 1.0, 1.0],
+        [1.0, 1.0, -1.0],
+    ];
+    let ys = [1.0, -1.0, -1.0, 1.0];
+    let sizes = [4, 4, 1];
+
+    let mlp = MLP::new(3, sizes.to_vec());
+    let params: Vec<Tensor> = mlp.parameters().concat();
+    let mut values: Vec<f64> = params.iter().map(|p| p.data()).collect();
+
+    // 같은 가중치의 Tensor 그래프와 기울기 비교
+    let loss = (&mlp.forward(&Tensor::from_vec(xs[0].to_vec()))[0] - ys[0]).pow(2.0);
+    loss.backward();
+
+    let tape = Tape::new();
+    let ps = tape.vars(&values);
+    let l = (forward(&ps, &sizes, &tape.vars(&xs[0]))[0] - ys[0]).pow(2.0);
+    l.backward();
+    assert!((l.data() - loss.data()).abs() < 1e-12);
+    for (p, t) in ps.iter().zip(&params) {
+        assert!((p.grad() - t.grad()).abs() < 1e-12);
+    }
+
+    // 학습: 매 step마다 tape를 비우고 같은 메모리를 재사용
+    let mut tape = Tape::with_capacity(1024);
+    let mut losses = vec![];
+    for _ in 0..50 {
+        tape.clear();
+        let ps = tape.vars(&values);
+        let total = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| (forward(&ps, &sizes, &tape.vars(x))[0] - y).pow(2.0))
+            .reduce(|acc, l| acc + l)
+            .unwrap();
+        total.backward();
+        losses.push(total.data());
+
+        let grads: Vec<f64> = ps.iter().map(|p| p.grad()).collect();
+        for (v, g) in values.iter_mut().zip(grads) {
+            *v -= 0.05 * g;
+        }
+    }
+    println!("loss: {:?} -> {:?}", losses[0], losses.last());
+    assert!(losses.last().unwrap() < &losses[0]);
+}
+
+#[test]
+fn test_deep_tape() {
+    let tape = Tape::with_capacity(1_000_001);
+    let x = tape.var(0.5);
+    let mut y = x;
+    for _ in 0..1_000_000 {
+        y = y + 1.0;
+    }
+    y.backward();
+    assert_eq!(x.grad(), 1.0);
+    assert_eq!(y.data(), 1_000_000.5);
+}